@@ -21,7 +21,7 @@
 //! This module provides common traits for visiting or rewriting tree
 //! data structures easily.
 
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
 type Result<T> = DaftResult<T>;
 
@@ -65,9 +65,32 @@ macro_rules! handle_transform_recursion {
 /// | combined with separate `f_down` and `f_up` closures | | [`transform_down_up`] |
 /// | combined with `f_down()` and `f_up()` in an object | [`visit`]  | [`rewrite`] |
 ///
-/// **Note**:while there is currently no in-place mutation API that uses `&mut
-/// TreeNode`, the transforming APIs are efficient and optimized to avoid
-/// cloning.
+/// **Note**: the transforming APIs above consume and return an owned tree,
+/// which is efficient and optimized to avoid cloning on its own. For
+/// callers that already hold a `&mut TreeNode` and want to avoid moving it
+/// out, [`Self::rewrite_in_place`], [`Self::transform_down_in_place`], and
+/// [`Self::transform_up_in_place`] wrap the owned-value APIs behind a
+/// `&mut self` signature (requires `Self: Default`). [`Self::rewrite_mut`]
+/// goes further and mutates the node through `&mut` at every level of the
+/// traversal (via a [`TreeNodeMutator`] rather than a [`TreeNodeRewriter`]),
+/// which matters for `Arc`-wrapped trees (see [`rewrite_arc_in_place`]):
+/// mutating a uniquely-owned `Arc` in place avoids reallocating it, unlike
+/// rebuilding it through [`DynTreeNode::with_new_arc_children`].
+///
+/// For traversals that need to thread extra context down the tree (not just
+/// transform it), see [`Self::transform_down_with_payload`],
+/// [`Self::transform_up_with_payload`], and [`Self::transform_with_payload`].
+/// [`PlanContext`]/[`ExprContext`] offer a complementary approach: wrapping a
+/// tree once with a payload per node rather than threading one through each
+/// traversal call.
+///
+/// For node types that implement [`ConcreteTreeNode`] (rather than the
+/// `Arc`-based [`DynTreeNode`]), [`ConcreteTreeNode::apply_ref`] and
+/// [`ConcreteTreeNode::visit_ref`] additionally support read-only traversals
+/// that hand back node references tied to the root's lifetime, and
+/// [`ConcreteTreeNode::iter_preorder`]/[`ConcreteTreeNode::iter_postorder`]
+/// offer lazy, non-recursive iteration for trees too deep to walk
+/// recursively.
 ///
 /// [`apply`]: Self::apply
 /// [`visit`]: Self::visit
@@ -397,6 +420,274 @@ pub trait TreeNode: Sized {
         transform_down_up_impl(self, &mut f_down, &mut f_up)
     }
 
+    /// Recursively rewrites the tree top-down (pre-order), threading a
+    /// per-child `payload` down from each node to its children.
+    ///
+    /// Unlike [`Self::transform_down`], `f` also receives the payload handed
+    /// down from the node's parent (or the initial `payload` for the root),
+    /// and returns one payload per child (in child order) to hand down to
+    /// this node's own children, in addition to the transformed node. This
+    /// is useful for algorithms that need to hand each branch a different
+    /// piece of contextual state, e.g. pushing per-side ordering
+    /// requirements down through a join. The returned payload `Vec` must
+    /// have exactly as many entries as the node has children after
+    /// rewriting; a mismatch is an error.
+    ///
+    /// # See Also
+    /// * [`Self::transform_up_with_payload`] for the bottom-up counterpart.
+    /// * [`Self::transform_with_payload`] for a combined top-down/bottom-up pass.
+    fn transform_down_with_payload<
+        P,
+        F: FnMut(Self, P) -> Result<Transformed<(Self, Vec<P>)>>,
+    >(
+        self,
+        payload: P,
+        f: &mut F,
+    ) -> Result<Transformed<Self>> {
+        fn transform_down_with_payload_impl<
+            N: TreeNode,
+            P,
+            F: FnMut(N, P) -> Result<Transformed<(N, Vec<P>)>>,
+        >(
+            node: N,
+            payload: P,
+            f: &mut F,
+        ) -> Result<Transformed<N>> {
+            let Transformed {
+                data: (node, child_payloads),
+                transformed,
+                tnr,
+            } = f(node, payload)?;
+            let mut num_children = 0;
+            node.apply_children(|_| {
+                num_children += 1;
+                Ok(TreeNodeRecursion::Continue)
+            })?;
+            if child_payloads.len() != num_children {
+                return Err(DaftError::ValueError(format!(
+                    "transform_down_with_payload: f returned {} payloads for a node with {num_children} children",
+                    child_payloads.len()
+                )));
+            }
+            let mut child_payloads = child_payloads.into_iter();
+            Transformed::new(node, transformed, tnr).transform_children(|n| {
+                n.map_children(|c| {
+                    let child_payload = child_payloads.next().ok_or_else(|| {
+                        DaftError::ValueError(
+                            "transform_down_with_payload: ran out of child payloads".to_string(),
+                        )
+                    })?;
+                    transform_down_with_payload_impl(c, child_payload, f)
+                })
+            })
+        }
+
+        transform_down_with_payload_impl(self, payload, f)
+    }
+
+    /// Recursively rewrites the tree bottom-up (post-order): children are
+    /// rewritten first, their emitted payloads are collected (in child
+    /// order) into a `Vec<P>`, and `f` is handed that `Vec` along with the
+    /// now-rewritten node to produce both the final node and a single
+    /// payload to hand up to its own parent.
+    ///
+    /// # See Also
+    /// * [`Self::transform_down_with_payload`] for the top-down counterpart.
+    fn transform_up_with_payload<P, F: FnMut(Self, Vec<P>) -> Result<Transformed<(Self, P)>>>(
+        self,
+        f: &mut F,
+    ) -> Result<Transformed<(Self, P)>> {
+        fn transform_up_with_payload_impl<
+            N: TreeNode,
+            P,
+            F: FnMut(N, Vec<P>) -> Result<Transformed<(N, P)>>,
+        >(
+            node: N,
+            f: &mut F,
+        ) -> Result<Transformed<(N, P)>> {
+            let mut child_payloads = Vec::new();
+            let rewritten = node.map_children(|c| {
+                let Transformed {
+                    data: (child, child_payload),
+                    transformed,
+                    tnr,
+                } = transform_up_with_payload_impl(c, f)?;
+                child_payloads.push(child_payload);
+                Ok(Transformed::new(child, transformed, tnr))
+            })?;
+            rewritten.transform_data(|n| f(n, child_payloads))
+        }
+
+        transform_up_with_payload_impl(self, f)
+    }
+
+    /// Combines [`Self::transform_down_with_payload`] and
+    /// [`Self::transform_up_with_payload`] into a single traversal: `f_down`
+    /// threads a `PD` payload down the tree (one per child) and rewrites
+    /// each node on the way down, then `f_up` rewrites each node on the way
+    /// back up, consuming the `Vec<PU>` of payloads its children emitted
+    /// and producing a single `PU` to hand to its own parent.
+    fn transform_with_payload<
+        PD,
+        PU,
+        FD: FnMut(Self, PD) -> Result<Transformed<(Self, Vec<PD>)>>,
+        FU: FnMut(Self, Vec<PU>) -> Result<Transformed<(Self, PU)>>,
+    >(
+        self,
+        payload: PD,
+        f_down: &mut FD,
+        f_up: &mut FU,
+    ) -> Result<Transformed<(Self, PU)>> {
+        fn transform_with_payload_impl<
+            N: TreeNode,
+            PD,
+            PU,
+            FD: FnMut(N, PD) -> Result<Transformed<(N, Vec<PD>)>>,
+            FU: FnMut(N, Vec<PU>) -> Result<Transformed<(N, PU)>>,
+        >(
+            node: N,
+            payload: PD,
+            f_down: &mut FD,
+            f_up: &mut FU,
+        ) -> Result<Transformed<(N, PU)>> {
+            let Transformed {
+                data: (node, child_payloads),
+                transformed,
+                tnr,
+            } = f_down(node, payload)?;
+            let mut num_children = 0;
+            node.apply_children(|_| {
+                num_children += 1;
+                Ok(TreeNodeRecursion::Continue)
+            })?;
+            if child_payloads.len() != num_children {
+                return Err(DaftError::ValueError(format!(
+                    "transform_with_payload: f_down returned {} payloads for a node with {num_children} children",
+                    child_payloads.len()
+                )));
+            }
+            let mut child_payloads = child_payloads.into_iter();
+            let mut up_payloads = Vec::new();
+            let rewritten = Transformed::new(node, transformed, tnr)
+                .transform_children(|n| {
+                    n.map_children(|c| {
+                        let child_payload = child_payloads.next().ok_or_else(|| {
+                            DaftError::ValueError(
+                                "transform_with_payload: ran out of child payloads".to_string(),
+                            )
+                        })?;
+                        let Transformed {
+                            data: (child, up_payload),
+                            transformed,
+                            tnr,
+                        } = transform_with_payload_impl(c, child_payload, f_down, f_up)?;
+                        up_payloads.push(up_payload);
+                        Ok(Transformed::new(child, transformed, tnr))
+                    })
+                })?;
+            rewritten.transform_data(|n| f_up(n, up_payloads))
+        }
+
+        transform_with_payload_impl(self, payload, f_down, f_up)
+    }
+
+    /// In-place variant of [`Self::rewrite`].
+    ///
+    /// `self` is temporarily replaced with `Self::default()` via
+    /// [`std::mem::take`] so the owned tree can be threaded through the
+    /// existing (owned-value) rewrite machinery without the caller having to
+    /// clone it out of a `&mut` reference first. Returns whether the tree was
+    /// changed.
+    fn rewrite_in_place<R: TreeNodeRewriter<Node = Self>>(
+        &mut self,
+        rewriter: &mut R,
+    ) -> Result<bool>
+    where
+        Self: Default,
+    {
+        let owned = std::mem::take(self);
+        let transformed = owned.rewrite(rewriter)?;
+        *self = transformed.data;
+        Ok(transformed.transformed)
+    }
+
+    /// In-place variant of [`Self::transform_down`]. See [`Self::rewrite_in_place`]
+    /// for why a `Default` bound is required.
+    fn transform_down_in_place<F: FnMut(Self) -> Result<Transformed<Self>>>(
+        &mut self,
+        f: F,
+    ) -> Result<bool>
+    where
+        Self: Default,
+    {
+        let owned = std::mem::take(self);
+        let transformed = owned.transform_down(f)?;
+        *self = transformed.data;
+        Ok(transformed.transformed)
+    }
+
+    /// In-place variant of [`Self::transform_up`]. See [`Self::rewrite_in_place`]
+    /// for why a `Default` bound is required.
+    fn transform_up_in_place<F: FnMut(Self) -> Result<Transformed<Self>>>(
+        &mut self,
+        f: F,
+    ) -> Result<bool>
+    where
+        Self: Default,
+    {
+        let owned = std::mem::take(self);
+        let transformed = owned.transform_up(f)?;
+        *self = transformed.data;
+        Ok(transformed.transformed)
+    }
+
+    /// Mutates each child of `self` in place via `f`, without moving `self`
+    /// itself out of its owning slot.
+    ///
+    /// The default implementation is built on [`Self::map_children`] via
+    /// [`std::mem::take`] (see [`Self::rewrite_in_place`] for why a
+    /// `Default` bound is required): a plain owned tree has no shared
+    /// ownership to preserve, so taking a child out, handing it to `f`, and
+    /// putting it back is exactly as cheap as mutating it "in place" would
+    /// be. [`DynTreeNode`]-based `Arc` trees don't have this luxury --
+    /// rebuilding a shared `Arc` child would lose its identity for every
+    /// other owner -- so they use [`rewrite_arc_in_place`] instead, which
+    /// mutates a uniquely-owned `Arc` child via [`Arc::get_mut`] to avoid
+    /// reallocating it at all.
+    fn map_children_mut<F: FnMut(&mut Self) -> Result<Transformed<()>>>(
+        &mut self,
+        mut f: F,
+    ) -> Result<Transformed<()>>
+    where
+        Self: Default,
+    {
+        let owned = std::mem::take(self);
+        let rewritten = owned.map_children(|mut child| {
+            let Transformed { transformed, tnr, .. } = f(&mut child)?;
+            Ok(Transformed::new(child, transformed, tnr))
+        })?;
+        let result = Transformed::new((), rewritten.transformed, rewritten.tnr);
+        *self = rewritten.data;
+        Ok(result)
+    }
+
+    /// In-place variant of [`Self::rewrite`] driven by a [`TreeNodeMutator`]
+    /// instead of a [`TreeNodeRewriter`]: `mutator`'s `f_down`/`f_up` mutate
+    /// `self` through `&mut` rather than replacing it by value. See
+    /// [`Self::rewrite_in_place`] for why a `Default` bound is required.
+    fn rewrite_mut<M: TreeNodeMutator<Node = Self>>(
+        &mut self,
+        mutator: &mut M,
+    ) -> Result<Transformed<()>>
+    where
+        Self: Default,
+    {
+        mutator
+            .f_down(self)?
+            .transform_children(|()| self.map_children_mut(|c| c.rewrite_mut(mutator)))?
+            .transform_parent(|()| mutator.f_up(self))
+    }
+
     /// Returns true if `f` returns true for any node in the tree.
     ///
     /// Stops recursion as soon as a matching node is found
@@ -516,6 +807,205 @@ pub trait TreeNodeRewriter: Sized {
     }
 }
 
+/// A mutator for recursively rewriting a [`TreeNode`] in place via
+/// [`TreeNode::rewrite_mut`].
+///
+/// Unlike [`TreeNodeRewriter`] (whose `f_down`/`f_up` take and return the
+/// node by value), `f_down`/`f_up` here receive the node by `&mut`
+/// reference and report whether they changed it via a `Transformed<()>`
+/// with no data of its own. This is what lets [`TreeNode::rewrite_mut`]
+/// mutate a node through a `&mut` reference at every level instead of
+/// moving the tree out of its slot just to hand it to a rewriter and move
+/// it back.
+///
+/// # See Also
+/// * [`TreeNodeRewriter`] for the owned-value equivalent.
+pub trait TreeNodeMutator: Sized {
+    /// The node type which is mutable.
+    type Node: TreeNode;
+
+    /// Invoked while traversing down the tree before any children are mutated.
+    /// Default implementation does nothing and continues recursion.
+    fn f_down(&mut self, _node: &mut Self::Node) -> Result<Transformed<()>> {
+        Ok(Transformed::no(()))
+    }
+
+    /// Invoked while traversing up the tree after all children have been mutated.
+    /// Default implementation does nothing and continues recursion.
+    fn f_up(&mut self, _node: &mut Self::Node) -> Result<Transformed<()>> {
+        Ok(Transformed::no(()))
+    }
+}
+
+/// Adapts a pair of closures into a [`TreeNodeRewriter`], for callers that
+/// want a single combined top-down/bottom-up pass (e.g. pushing a context
+/// down and folding it back up once children are simplified) without
+/// declaring a dedicated struct just to hold `f_down`/`f_up`.
+///
+/// ```ignore
+/// tree.rewrite(&mut FnRewriter::new(
+///     |node| { /* top-down */ Ok(Transformed::no(node)) },
+///     |node| { /* bottom-up */ Ok(Transformed::no(node)) },
+/// ))?;
+/// ```
+pub struct FnRewriter<N, D, U> {
+    f_down: D,
+    f_up: U,
+    _node: std::marker::PhantomData<N>,
+}
+
+impl<N, D, U> FnRewriter<N, D, U>
+where
+    N: TreeNode,
+    D: FnMut(N) -> Result<Transformed<N>>,
+    U: FnMut(N) -> Result<Transformed<N>>,
+{
+    pub fn new(f_down: D, f_up: U) -> Self {
+        Self {
+            f_down,
+            f_up,
+            _node: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<N, D, U> TreeNodeRewriter for FnRewriter<N, D, U>
+where
+    N: TreeNode,
+    D: FnMut(N) -> Result<Transformed<N>>,
+    U: FnMut(N) -> Result<Transformed<N>>,
+{
+    type Node = N;
+
+    fn f_down(&mut self, node: Self::Node) -> Result<Transformed<Self::Node>> {
+        (self.f_down)(node)
+    }
+
+    fn f_up(&mut self, node: Self::Node) -> Result<Transformed<Self::Node>> {
+        (self.f_up)(node)
+    }
+}
+
+/// The recorded `f_down`/`f_up` outcome for a single node visited by
+/// [`rewrite_traced`], plus the traces of its children in visitation order.
+#[derive(Debug, Clone)]
+pub struct NodeTrace {
+    pub label: String,
+    pub f_down: TreeNodeRecursion,
+    pub down_transformed: bool,
+    pub f_up: TreeNodeRecursion,
+    pub up_transformed: bool,
+    pub children: Vec<NodeTrace>,
+}
+
+impl NodeTrace {
+    fn fmt_node(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        prefix: &str,
+        connector: &str,
+        child_prefix: &str,
+    ) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{prefix}{connector}{} [down: {:?}{}] [up: {:?}{}]",
+            self.label,
+            self.f_down,
+            if self.down_transformed { ", transformed" } else { "" },
+            self.f_up,
+            if self.up_transformed { ", transformed" } else { "" },
+        )?;
+        let last = self.children.len().saturating_sub(1);
+        for (i, child) in self.children.iter().enumerate() {
+            let (child_connector, grandchild_prefix) = if i == last {
+                ("└── ", format!("{child_prefix}    "))
+            } else {
+                ("├── ", format!("{child_prefix}│   "))
+            };
+            child.fmt_node(f, child_prefix, child_connector, &grandchild_prefix)?;
+        }
+        Ok(())
+    }
+}
+
+/// The full trace produced by [`rewrite_traced`]. Its [`Display`](std::fmt::Display)
+/// impl renders an indented ASCII tree — one line per visited node, with
+/// `├──`/`└──` connectors mirroring tree depth — annotating the
+/// [`TreeNodeRecursion`] returned by `f_down`/`f_up` and whether the node was
+/// changed, so a rule author can see exactly where a `Jump` or `Stop`
+/// short-circuited the traversal.
+#[derive(Debug, Clone)]
+pub struct TraversalTrace {
+    pub root: NodeTrace,
+}
+
+impl std::fmt::Display for TraversalTrace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.root.fmt_node(f, "", "", "")
+    }
+}
+
+/// Like [`TreeNode::rewrite`], but additionally records a [`TraversalTrace`]
+/// of every `f_down`/`f_up` invocation: the [`TreeNodeRecursion`] it
+/// returned, whether it changed the node, and (via `label`) a short
+/// human-readable name for the node. Meant for debugging a rewrite rule
+/// whose output doesn't match expectations — `println!("{trace}")` prints an
+/// indented ASCII tree pinpointing exactly where `Jump`/`Stop`
+/// short-circuited the traversal.
+pub fn rewrite_traced<N, R>(
+    node: N,
+    label: &dyn Fn(&N) -> String,
+    rewriter: &mut R,
+) -> Result<(Transformed<N>, TraversalTrace)>
+where
+    N: TreeNode,
+    R: TreeNodeRewriter<Node = N>,
+{
+    fn go<N, R>(
+        node: N,
+        label: &dyn Fn(&N) -> String,
+        rewriter: &mut R,
+    ) -> Result<(Transformed<N>, NodeTrace)>
+    where
+        N: TreeNode,
+        R: TreeNodeRewriter<Node = N>,
+    {
+        let name = label(&node);
+        let down = rewriter.f_down(node)?;
+        let down_tnr = down.tnr;
+        let down_transformed = down.transformed;
+
+        let mut child_traces = Vec::new();
+        let after_children = down.transform_children(|n| {
+            n.map_children(|c| {
+                let (result, trace) = go(c, label, rewriter)?;
+                child_traces.push(trace);
+                Ok(result)
+            })
+        })?;
+
+        let mut up_transformed = false;
+        let final_result = after_children.transform_parent(|n| {
+            let up = rewriter.f_up(n)?;
+            up_transformed = up.transformed;
+            Ok(up)
+        })?;
+
+        let trace = NodeTrace {
+            label: name,
+            f_down: down_tnr,
+            down_transformed,
+            f_up: final_result.tnr,
+            up_transformed,
+            children: child_traces,
+        };
+
+        Ok((final_result, trace))
+    }
+
+    go(node, label, rewriter)
+}
+
 /// Controls how [`TreeNode`] recursions should proceed.
 #[derive(Debug, PartialEq, Clone, Copy, Eq)]
 pub enum TreeNodeRecursion {
@@ -750,6 +1240,18 @@ pub trait TreeNodeIterator: Iterator {
         self,
         f: F,
     ) -> Result<Transformed<Vec<Self::Item>>>;
+
+    /// Like [`Self::map_until_stop_and_collect`], but also passes each item's
+    /// position in the sequence to `f`. Useful when a rewrite needs to know
+    /// which child it's looking at (e.g. to report "argument 2 of `f`" in an
+    /// error, or to treat the first/last sibling specially) without having
+    /// to zip the iterator with a separate counter at each call site.
+    fn map_until_stop_and_collect_indexed<
+        F: FnMut(usize, Self::Item) -> Result<Transformed<Self::Item>>,
+    >(
+        self,
+        f: F,
+    ) -> Result<Transformed<Vec<Self::Item>>>;
 }
 
 impl<I: Iterator> TreeNodeIterator for I {
@@ -802,6 +1304,29 @@ impl<I: Iterator> TreeNodeIterator for I {
         .collect::<Result<Vec<_>>>()
         .map(|data| Transformed::new(data, transformed, tnr))
     }
+
+    fn map_until_stop_and_collect_indexed<
+        F: FnMut(usize, Self::Item) -> Result<Transformed<Self::Item>>,
+    >(
+        self,
+        mut f: F,
+    ) -> Result<Transformed<Vec<Self::Item>>> {
+        let mut tnr = TreeNodeRecursion::Continue;
+        let mut transformed = false;
+        self.enumerate()
+            .map(|(i, item)| match tnr {
+                TreeNodeRecursion::Continue | TreeNodeRecursion::Jump => {
+                    f(i, item).map(|result| {
+                        tnr = result.tnr;
+                        transformed |= result.transformed;
+                        result.data
+                    })
+                }
+                TreeNodeRecursion::Stop => Ok(item),
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(|data| Transformed::new(data, transformed, tnr))
+    }
 }
 
 /// Transformation helper to process a heterogeneous sequence of tree node containing
@@ -882,6 +1407,27 @@ pub trait DynTreeNode {
 
     /// Constructs a new node with the specified children.
     fn with_new_arc_children(self: Arc<Self>, new_children: Vec<Arc<Self>>) -> Result<Arc<Self>>;
+
+    /// Borrowed view of this node's children, for read-only traversals
+    /// ([`TreeNode::apply`], [`TreeNode::exists`]) that don't need ownership.
+    ///
+    /// The default implementation falls back to [`Self::arc_children`],
+    /// which clones every child `Arc` (bumping refcounts) just to iterate
+    /// them. Override this to return [`std::borrow::Cow::Borrowed`] into
+    /// whatever `Vec<Arc<Self>>` the node already stores its children in, to
+    /// skip that allocation-and-clone on every read-only walk.
+    fn ref_children(&self) -> std::borrow::Cow<'_, [Arc<Self>]> {
+        std::borrow::Cow::Owned(self.arc_children())
+    }
+
+    /// Mutable view of this node's children, for [`rewrite_arc_in_place`]'s
+    /// in-place traversal: once it knows (via [`Arc::get_mut`]) that this
+    /// node isn't shared with any other owner, it mutates a child's `Arc`
+    /// directly through this slice instead of rebuilding the node via
+    /// [`Self::with_new_arc_children`]. Override this alongside
+    /// [`Self::arc_children`] to return `&mut` into whatever field actually
+    /// stores the children.
+    fn arc_children_mut(&mut self) -> &mut [Arc<Self>];
 }
 
 /// Blanket implementation for any `Arc<T>` where `T` implements [`DynTreeNode`]
@@ -890,7 +1436,7 @@ impl<T: DynTreeNode + ?Sized> TreeNode for Arc<T> {
         &self,
         f: F,
     ) -> Result<TreeNodeRecursion> {
-        self.arc_children().iter().apply_until_stop(f)
+        self.ref_children().iter().apply_until_stop(f)
     }
 
     fn map_children<F: FnMut(Self) -> Result<Transformed<Self>>>(
@@ -913,6 +1459,147 @@ impl<T: DynTreeNode + ?Sized> TreeNode for Arc<T> {
     }
 }
 
+/// Drives a [`TreeNodeMutator`] over an `Arc`-wrapped [`DynTreeNode`] tree,
+/// mutating `node` (and recursively, its children) in place rather than
+/// rebuilding it through [`DynTreeNode::with_new_arc_children`].
+///
+/// Note this doesn't use [`Arc::make_mut`]: that would require `T: Clone`,
+/// which conflicts with [`DynTreeNode`]'s explicit support for unsized trait
+/// objects (`Arc<dyn T>`) -- `Clone` isn't implementable for an unsized
+/// `Self`. Instead, [`map_arc_children_mut`] mutates a child via
+/// [`Arc::get_mut`] when it's uniquely owned, and only falls back to
+/// [`DynTreeNode::with_new_arc_children`] (still cheaper than the owned
+/// `rewrite`, since only `node` itself is rebuilt, not every ancestor) when
+/// the `Arc` turns out to be shared.
+fn rewrite_arc_mut<T, M>(node: &mut Arc<T>, mutator: &mut M) -> Result<Transformed<()>>
+where
+    T: DynTreeNode + ?Sized,
+    M: TreeNodeMutator<Node = Arc<T>>,
+{
+    mutator
+        .f_down(node)?
+        .transform_children(|()| map_arc_children_mut(node, mutator))?
+        .transform_parent(|()| mutator.f_up(node))
+}
+
+/// Mutates each of `node`'s children in place via [`rewrite_arc_mut`].
+///
+/// If `node` is uniquely owned, [`Arc::get_mut`] hands back a `&mut T`
+/// directly, and each child is mutated through [`DynTreeNode::arc_children_mut`]
+/// without `node` itself ever being reallocated. Otherwise `node` is shared
+/// with another owner, so mutating through it would be observable from
+/// that owner too -- `node`'s children are rewritten from a cloned list
+/// (cheap: cloning an `Arc` is a refcount bump, not a deep copy of `T`) and
+/// `node` is only rebuilt via [`DynTreeNode::with_new_arc_children`] if one
+/// of them actually changed. Either way, a child that wasn't touched keeps
+/// its original `Arc` (`Arc::ptr_eq` holds).
+fn map_arc_children_mut<T, M>(node: &mut Arc<T>, mutator: &mut M) -> Result<Transformed<()>>
+where
+    T: DynTreeNode + ?Sized,
+    M: TreeNodeMutator<Node = Arc<T>>,
+{
+    if node.arc_children().is_empty() {
+        return Ok(Transformed::no(()));
+    }
+
+    if let Some(unique) = Arc::get_mut(node) {
+        return rewrite_arc_children_mut(unique.arc_children_mut(), mutator);
+    }
+
+    let mut children = node.arc_children();
+    let result = rewrite_arc_children_mut(&mut children, mutator)?;
+    if result.transformed {
+        *node = node.clone().with_new_arc_children(children)?;
+    }
+    Ok(result)
+}
+
+/// Mutates each `Arc` in `children` in place via [`rewrite_arc_mut`],
+/// stopping early (without running the rest) as soon as one reports
+/// [`TreeNodeRecursion::Stop`].
+fn rewrite_arc_children_mut<T, M>(
+    children: &mut [Arc<T>],
+    mutator: &mut M,
+) -> Result<Transformed<()>>
+where
+    T: DynTreeNode + ?Sized,
+    M: TreeNodeMutator<Node = Arc<T>>,
+{
+    let mut transformed = false;
+    let mut tnr = TreeNodeRecursion::Continue;
+    for child in children {
+        let result = rewrite_arc_mut(child, mutator)?;
+        transformed |= result.transformed;
+        tnr = result.tnr;
+        if tnr == TreeNodeRecursion::Stop {
+            break;
+        }
+    }
+    Ok(Transformed::new((), transformed, tnr))
+}
+
+/// In-place variant of [`TreeNode::rewrite`] for `Arc`-wrapped [`DynTreeNode`]
+/// trees, mirroring [`TreeNode::rewrite_in_place`]'s `&mut self` signature so
+/// callers holding `&mut Arc<T>` (e.g. a field on a larger plan/expression
+/// node) don't have to move it out and back in.
+///
+/// This is driven by [`rewrite_arc_mut`], so a node is only ever rebuilt
+/// through [`DynTreeNode::with_new_arc_children`] if it's actually shared
+/// with another owner; a uniquely-owned node (the common case for a plan or
+/// expression tree with a single owner) is mutated through [`Arc::get_mut`]
+/// without touching its allocation at all, and either way an untouched
+/// subtree keeps the exact `Arc` it started with (`Arc::ptr_eq` holds).
+pub fn rewrite_arc_in_place<T, R>(node: &mut Arc<T>, rewriter: &mut R) -> Result<bool>
+where
+    T: DynTreeNode + ?Sized,
+    R: TreeNodeRewriter<Node = Arc<T>>,
+{
+    let mut mutator = RewriterMutator { rewriter };
+    let result = rewrite_arc_mut(node, &mut mutator)?;
+    Ok(result.transformed)
+}
+
+/// Adapts a [`TreeNodeRewriter`] (whose `f_down`/`f_up` replace a node
+/// wholesale, taking and returning it by value) into a [`TreeNodeMutator`]
+/// (whose `f_down`/`f_up` mutate a node through `&mut`), so
+/// [`rewrite_arc_in_place`] can reuse [`rewrite_arc_mut`]'s
+/// `Arc::get_mut`-based traversal instead of duplicating it.
+///
+/// Moving the node out to hand to `rewriter` and back is cheap here
+/// specifically because `Self::Node` is an `Arc`: cloning it is a refcount
+/// bump, never a deep clone of the wrapped `T`.
+struct RewriterMutator<'a, R> {
+    rewriter: &'a mut R,
+}
+
+impl<T, R> TreeNodeMutator for RewriterMutator<'_, R>
+where
+    T: DynTreeNode + ?Sized,
+    R: TreeNodeRewriter<Node = Arc<T>>,
+{
+    type Node = Arc<T>;
+
+    fn f_down(&mut self, node: &mut Self::Node) -> Result<Transformed<()>> {
+        let Transformed {
+            data,
+            transformed,
+            tnr,
+        } = self.rewriter.f_down(node.clone())?;
+        *node = data;
+        Ok(Transformed::new((), transformed, tnr))
+    }
+
+    fn f_up(&mut self, node: &mut Self::Node) -> Result<Transformed<()>> {
+        let Transformed {
+            data,
+            transformed,
+            tnr,
+        } = self.rewriter.f_up(node.clone())?;
+        *node = data;
+        Ok(Transformed::new((), transformed, tnr))
+    }
+}
+
 /// Instead of implementing [`TreeNode`], it's recommended to implement a [`ConcreteTreeNode`] for
 /// trees that contain nodes with payloads.
 ///
@@ -927,6 +1614,385 @@ pub trait ConcreteTreeNode: Sized {
 
     /// Reattaches updated child nodes to the node, returning the updated node.
     fn with_new_children(self, children: Vec<Self>) -> Result<Self>;
+
+    /// Like [`TreeNode::apply`], but because [`Self::children`] hands back
+    /// real `&'n Self` references instead of calling a closure with a
+    /// re-borrowed lifetime, `f` can stash away (or return) node references
+    /// that outlive the traversal itself, tied to the lifetime of the root
+    /// `self` passed in here.
+    fn apply_ref<'n, F: FnMut(&'n Self) -> Result<TreeNodeRecursion>>(
+        &'n self,
+        mut f: F,
+    ) -> Result<TreeNodeRecursion> {
+        fn apply_ref_impl<'n, N: ConcreteTreeNode, F: FnMut(&'n N) -> Result<TreeNodeRecursion>>(
+            node: &'n N,
+            f: &mut F,
+        ) -> Result<TreeNodeRecursion> {
+            f(node)?.visit_children(|| {
+                for child in node.children() {
+                    match apply_ref_impl(child, f)? {
+                        TreeNodeRecursion::Continue => {}
+                        other => return Ok(other),
+                    }
+                }
+                Ok(TreeNodeRecursion::Continue)
+            })
+        }
+
+        apply_ref_impl(self, &mut f)
+    }
+
+    /// Like [`TreeNode::visit`], but using [`TreeNodeRefVisitor`] so that
+    /// `f_down`/`f_up` receive `&'n Self` references tied to the root rather
+    /// than to each individual callback invocation.
+    fn visit_ref<'n, V: TreeNodeRefVisitor<'n, Node = Self>>(
+        &'n self,
+        visitor: &mut V,
+    ) -> Result<TreeNodeRecursion> {
+        fn visit_ref_impl<'n, N: ConcreteTreeNode, V: TreeNodeRefVisitor<'n, Node = N>>(
+            node: &'n N,
+            visitor: &mut V,
+        ) -> Result<TreeNodeRecursion> {
+            visitor.f_down(node)?.visit_children(|| {
+                let mut tnr = TreeNodeRecursion::Continue;
+                for child in node.children() {
+                    tnr = visit_ref_impl(child, visitor)?;
+                    if tnr == TreeNodeRecursion::Stop {
+                        return Ok(tnr);
+                    }
+                }
+                Ok(tnr)
+            })?
+            .visit_parent(|| visitor.f_up(node))
+        }
+
+        visit_ref_impl(self, visitor)
+    }
+
+    /// Returns a reference to the first node (in pre-order) for which `f`
+    /// returns true, tied to the lifetime of `self` rather than to a
+    /// visitor callback.
+    fn find_ref<'n, F: Fn(&'n Self) -> bool>(&'n self, f: F) -> Option<&'n Self> {
+        let mut found = None;
+        let _ = self.apply_ref(|n| {
+            Ok(if f(n) {
+                found = Some(n);
+                TreeNodeRecursion::Stop
+            } else {
+                TreeNodeRecursion::Continue
+            })
+        });
+        found
+    }
+
+    /// Returns a lazy, non-recursive pre-order iterator over this node and
+    /// its descendants. Unlike [`Self::apply_ref`], which visits every node
+    /// eagerly via a callback, this produces items on demand and never
+    /// recurses, so it can walk trees too deep for the call stack.
+    fn iter_preorder(&self) -> PreorderIter<'_, Self> {
+        PreorderIter { stack: vec![self] }
+    }
+
+    /// Returns a lazy, non-recursive post-order iterator over this node and
+    /// its descendants. See [`Self::iter_preorder`].
+    fn iter_postorder(&self) -> PostorderIter<'_, Self> {
+        PostorderIter {
+            stack: vec![(self, 0)],
+        }
+    }
+
+    /// Non-recursive equivalent of [`TreeNode::transform_down`], for trees
+    /// too deep to rewrite with the native call stack (e.g. a long chain of
+    /// nested `AND`/`OR` predicates). Produces exactly the same rewritten
+    /// tree, `transformed` flag, and final [`TreeNodeRecursion`] as the
+    /// recursive version, using an explicit work stack instead of recursion.
+    fn transform_down_iter<F: FnMut(Self) -> Result<Transformed<Self>>>(
+        self,
+        f: &mut F,
+    ) -> Result<Transformed<Self>> {
+        enum Action<N> {
+            /// A node awaiting its own `f` call.
+            Enter(N),
+            /// A node whose own `f` call landed on `Continue`, with its
+            /// (already detached) children awaiting their own traversal and
+            /// the node's own `transformed` flag to merge back in once
+            /// they're done.
+            Rebuild(N, usize, bool),
+        }
+
+        let mut stack = vec![Action::Enter(self)];
+        let mut results: Vec<Transformed<Self>> = Vec::new();
+        let mut stopped = false;
+
+        while let Some(action) = stack.pop() {
+            match action {
+                Action::Enter(node) => {
+                    if stopped {
+                        results.push(Transformed::new(node, false, TreeNodeRecursion::Stop));
+                        continue;
+                    }
+                    let out = f(node)?;
+                    match out.tnr {
+                        TreeNodeRecursion::Stop => {
+                            stopped = true;
+                            results.push(Transformed::new(
+                                out.data,
+                                out.transformed,
+                                TreeNodeRecursion::Stop,
+                            ));
+                        }
+                        // A `Jump`ed node's children are left completely
+                        // untouched, and the subtree reports back `Continue`
+                        // so its own siblings keep traversing normally.
+                        TreeNodeRecursion::Jump => {
+                            results.push(Transformed::new(
+                                out.data,
+                                out.transformed,
+                                TreeNodeRecursion::Continue,
+                            ));
+                        }
+                        TreeNodeRecursion::Continue => {
+                            let (bare_node, children) = out.data.take_children();
+                            if children.is_empty() {
+                                results.push(Transformed::new(
+                                    bare_node,
+                                    out.transformed,
+                                    TreeNodeRecursion::Continue,
+                                ));
+                            } else {
+                                stack.push(Action::Rebuild(
+                                    bare_node,
+                                    children.len(),
+                                    out.transformed,
+                                ));
+                                for child in children.into_iter().rev() {
+                                    stack.push(Action::Enter(child));
+                                }
+                            }
+                        }
+                    }
+                }
+                Action::Rebuild(bare_node, num_children, own_transformed) => {
+                    let mut children = Vec::with_capacity(num_children);
+                    for _ in 0..num_children {
+                        children.push(results.pop().expect("missing child result"));
+                    }
+                    children.reverse();
+                    let children_transformed = children.iter().any(|c| c.transformed);
+                    // The last processed child's `tnr` is this subtree's
+                    // outgoing `tnr`, mirroring `map_until_stop_and_collect`.
+                    let tnr = children
+                        .last()
+                        .map_or(TreeNodeRecursion::Continue, |c| c.tnr);
+                    let children_data = children.into_iter().map(|c| c.data).collect();
+                    let node = bare_node.with_new_children(children_data)?;
+                    if tnr == TreeNodeRecursion::Stop {
+                        stopped = true;
+                    }
+                    results.push(Transformed::new(
+                        node,
+                        own_transformed || children_transformed,
+                        tnr,
+                    ));
+                }
+            }
+        }
+
+        Ok(results.pop().expect("exactly one result remains"))
+    }
+
+    /// Non-recursive equivalent of [`TreeNode::transform_up`]. See
+    /// [`Self::transform_down_iter`] for the motivating use case; this
+    /// produces the exact same rewritten tree, `transformed` flag, and final
+    /// [`TreeNodeRecursion`] as the recursive post-order version.
+    fn transform_up_iter<F: FnMut(Self) -> Result<Transformed<Self>>>(
+        self,
+        f: &mut F,
+    ) -> Result<Transformed<Self>> {
+        enum Action<N> {
+            /// A node whose children haven't been visited yet.
+            Call(N),
+            /// A node (with children already detached) awaiting its
+            /// children's results so it can be reassembled and (maybe)
+            /// passed to `f` itself.
+            Handle(N, usize),
+        }
+
+        let mut stack = vec![Action::Call(self)];
+        let mut results: Vec<Transformed<Self>> = Vec::new();
+        let mut stopped = false;
+
+        while let Some(action) = stack.pop() {
+            match action {
+                Action::Call(node) => {
+                    if stopped {
+                        results.push(Transformed::new(node, false, TreeNodeRecursion::Stop));
+                        continue;
+                    }
+                    let (bare_node, children) = node.take_children();
+                    if children.is_empty() {
+                        let out = f(bare_node)?;
+                        if out.tnr == TreeNodeRecursion::Stop {
+                            stopped = true;
+                        }
+                        results.push(out);
+                    } else {
+                        stack.push(Action::Handle(bare_node, children.len()));
+                        for child in children.into_iter().rev() {
+                            stack.push(Action::Call(child));
+                        }
+                    }
+                }
+                Action::Handle(bare_node, num_children) => {
+                    let mut children = Vec::with_capacity(num_children);
+                    for _ in 0..num_children {
+                        children.push(results.pop().expect("missing child result"));
+                    }
+                    children.reverse();
+                    let children_transformed = children.iter().any(|c| c.transformed);
+                    // Mirrors `map_until_stop_and_collect`: the last
+                    // processed child's `tnr` decides whether `f` runs on
+                    // the reassembled parent at all.
+                    let tnr = children
+                        .last()
+                        .map_or(TreeNodeRecursion::Continue, |c| c.tnr);
+                    let children_data = children.into_iter().map(|c| c.data).collect();
+                    let node = bare_node.with_new_children(children_data)?;
+
+                    match tnr {
+                        TreeNodeRecursion::Continue => {
+                            let out = f(node)?;
+                            if out.tnr == TreeNodeRecursion::Stop {
+                                stopped = true;
+                            }
+                            results.push(Transformed::new(
+                                out.data,
+                                out.transformed || children_transformed,
+                                out.tnr,
+                            ));
+                        }
+                        TreeNodeRecursion::Jump | TreeNodeRecursion::Stop => {
+                            if tnr == TreeNodeRecursion::Stop {
+                                stopped = true;
+                            }
+                            results.push(Transformed::new(node, children_transformed, tnr));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(results.pop().expect("exactly one result remains"))
+    }
+}
+
+/// Implements [`ConcreteTreeNode`] for a struct whose children live in a
+/// `children: Vec<Self>` field, which covers the overwhelming majority of
+/// implementors (see the macro's own tests for an example). This collapses
+/// the boilerplate `children`/`take_children`/`with_new_children` trio
+/// (identical across every such implementor except for the type name) into a
+/// single invocation:
+///
+/// ```ignore
+/// struct MyNode {
+///     children: Vec<Self>,
+///     data: String,
+/// }
+/// common_treenode::impl_concrete_tree_node_for_vec_children!(MyNode);
+/// ```
+#[macro_export]
+macro_rules! impl_concrete_tree_node_for_vec_children {
+    ($ty:ty) => {
+        impl $crate::ConcreteTreeNode for $ty {
+            fn children(&self) -> ::std::vec::Vec<&Self> {
+                self.children.iter().collect()
+            }
+
+            fn take_children(self) -> (Self, ::std::vec::Vec<Self>) {
+                let children = self.children;
+                (
+                    Self {
+                        children: ::std::vec::Vec::new(),
+                        ..self
+                    },
+                    children,
+                )
+            }
+
+            fn with_new_children(
+                mut self,
+                children: ::std::vec::Vec<Self>,
+            ) -> ::common_error::DaftResult<Self> {
+                self.children = children;
+                Ok(self)
+            }
+        }
+    };
+}
+
+/// Lazy, stack-based pre-order iterator produced by [`ConcreteTreeNode::iter_preorder`].
+pub struct PreorderIter<'n, N: ConcreteTreeNode> {
+    stack: Vec<&'n N>,
+}
+
+impl<'n, N: ConcreteTreeNode> Iterator for PreorderIter<'n, N> {
+    type Item = &'n N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        // Push children in reverse so the leftmost child is popped (and thus
+        // visited) first, preserving left-to-right pre-order.
+        let mut children = node.children();
+        children.reverse();
+        self.stack.extend(children);
+        Some(node)
+    }
+}
+
+/// Lazy, stack-based post-order iterator produced by [`ConcreteTreeNode::iter_postorder`].
+///
+/// Each stack frame tracks the index of the next child still to be
+/// descended into, so a node is only popped (and yielded) once all of its
+/// children have been yielded first — without recursing.
+pub struct PostorderIter<'n, N: ConcreteTreeNode> {
+    stack: Vec<(&'n N, usize)>,
+}
+
+impl<'n, N: ConcreteTreeNode> Iterator for PostorderIter<'n, N> {
+    type Item = &'n N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, idx) = self.stack.last_mut()?;
+            let children = node.children();
+            if *idx < children.len() {
+                let child = children[*idx];
+                *idx += 1;
+                self.stack.push((child, 0));
+            } else {
+                return self.stack.pop().map(|(node, _)| node);
+            }
+        }
+    }
+}
+
+/// A reference-preserving counterpart to [`TreeNodeVisitor`] for use with
+/// [`ConcreteTreeNode::visit_ref`]. `f_down`/`f_up` receive `&'n Node`
+/// references tied to the traversal root's lifetime `'n`, so a visitor can
+/// collect node references to use after the traversal completes.
+pub trait TreeNodeRefVisitor<'n>: Sized {
+    /// The node type which is visitable.
+    type Node: ConcreteTreeNode;
+
+    /// Invoked while traversing down the tree, before any children are visited.
+    fn f_down(&mut self, _node: &'n Self::Node) -> Result<TreeNodeRecursion> {
+        Ok(TreeNodeRecursion::Continue)
+    }
+
+    /// Invoked while traversing up the tree after children are visited.
+    fn f_up(&mut self, _node: &'n Self::Node) -> Result<TreeNodeRecursion> {
+        Ok(TreeNodeRecursion::Continue)
+    }
 }
 
 impl<T: ConcreteTreeNode> TreeNode for T {
@@ -953,16 +2019,145 @@ impl<T: ConcreteTreeNode> TreeNode for T {
     }
 }
 
+/// Repeatedly applies a rewrite `f` to `node` until it reports no further
+/// change (a fixed point), guarding against unbounded optimizer loops with
+/// both a maximum iteration count and a cycle check.
+///
+/// Optimizer rules are usually applied to a plan/expression repeatedly until
+/// it stops changing, but a buggy or adversarial combination of rules can
+/// oscillate between a small set of states forever instead of converging.
+/// This helper bounds the loop to `max_iterations`, and additionally bails
+/// out early if a rewrite produces a state already seen earlier in the loop.
+///
+/// # See Also
+/// * [`TreeNode::transform`], [`TreeNode::rewrite`] for single-pass rewrites.
+pub fn transform_until_fixed_point<N, F>(mut node: N, max_iterations: usize, mut f: F) -> Result<N>
+where
+    N: Clone + PartialEq,
+    F: FnMut(N) -> Result<Transformed<N>>,
+{
+    let mut seen = Vec::with_capacity(max_iterations.min(16));
+    seen.push(node.clone());
+
+    for _ in 0..max_iterations {
+        let transformed = f(node)?;
+        if !transformed.transformed {
+            return Ok(transformed.data);
+        }
+        if seen.contains(&transformed.data) {
+            // Cycle detected: the rewrite oscillates between states instead
+            // of converging, so stop here rather than looping forever.
+            return Ok(transformed.data);
+        }
+        seen.push(transformed.data.clone());
+        node = transformed.data;
+    }
+
+    Ok(node)
+}
+
+/// Generic wrapper pairing a tree node `N` with an auxiliary payload `T`,
+/// plus the same wrapper recursively applied to its children.
+///
+/// This lets optimizer passes thread per-node data (e.g. required columns,
+/// schema statistics) alongside a plan or expression tree without modifying
+/// the underlying node type, and without re-deriving the tree's shape: a
+/// `Context` mirrors its wrapped node's children one-for-one.
+///
+/// [`PlanContext`] and [`ExprContext`] are the two node-specific aliases
+/// used throughout the optimizer: one wraps a logical plan node with
+/// plan-level payloads, the other wraps an expression node with
+/// expression-level payloads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Context<N, T> {
+    pub node: N,
+    pub data: T,
+    pub children: Vec<Self>,
+}
+
+impl<N, T> Context<N, T> {
+    pub fn new(node: N, data: T, children: Vec<Self>) -> Self {
+        Self {
+            node,
+            data,
+            children,
+        }
+    }
+}
+
+impl<N, T: Default> Context<N, T> {
+    /// Wraps `node` (and its already-wrapped `children`) with a fresh
+    /// `T::default()` payload.
+    pub fn new_default(node: N, children: Vec<Self>) -> Self {
+        Self::new(node, T::default(), children)
+    }
+}
+
+impl<N: ConcreteTreeNode, T: Default> Context<N, T> {
+    /// Recursively wraps a plain node tree with a fresh `T::default()`
+    /// payload at every level, walking down via `N`'s own
+    /// [`ConcreteTreeNode::take_children`] -- the one-shot counterpart to
+    /// calling [`Self::new_default`] by hand, bottom-up, for every node in
+    /// the tree.
+    pub fn from_tree(node: N) -> Self {
+        let (node, children) = node.take_children();
+        let children = children.into_iter().map(Self::from_tree).collect();
+        Self::new_default(node, children)
+    }
+}
+
+impl<N: ConcreteTreeNode, T> Context<N, T> {
+    /// Unwraps this `Context` tree back into the bare node tree, discarding
+    /// every payload -- the inverse of [`Self::from_tree`].
+    pub fn into_tree(self) -> Result<N> {
+        let children = self
+            .children
+            .into_iter()
+            .map(Self::into_tree)
+            .collect::<Result<Vec<_>>>()?;
+        self.node.with_new_children(children)
+    }
+}
+
+impl<N, T> ConcreteTreeNode for Context<N, T> {
+    fn children(&self) -> Vec<&Self> {
+        self.children.iter().collect()
+    }
+
+    fn take_children(self) -> (Self, Vec<Self>) {
+        let children = self.children;
+        (
+            Self {
+                node: self.node,
+                data: self.data,
+                children: vec![],
+            },
+            children,
+        )
+    }
+
+    fn with_new_children(mut self, children: Vec<Self>) -> Result<Self> {
+        self.children = children;
+        Ok(self)
+    }
+}
+
+/// A [`Context`] wrapping a logical plan node with a per-node payload `T`.
+pub type PlanContext<N, T> = Context<N, T>;
+/// A [`Context`] wrapping an expression node with a per-node payload `T`.
+pub type ExprContext<N, T> = Context<N, T>;
+
 #[cfg(test)]
 mod tests {
-    use std::fmt::Display;
+    use std::{fmt::Display, sync::Arc};
 
     use crate::{
-        Result, Transformed, TreeNode, TreeNodeIterator, TreeNodeRecursion, TreeNodeRewriter,
-        TreeNodeVisitor,
+        rewrite_arc_in_place, rewrite_traced, transform_until_fixed_point, ConcreteTreeNode,
+        DynTreeNode, FnRewriter, Result, Transformed, TreeNode, TreeNodeIterator, TreeNodeMutator,
+        TreeNodeRecursion, TreeNodeRefVisitor, TreeNodeRewriter, TreeNodeVisitor,
     };
 
-    #[derive(PartialEq, Debug)]
+    #[derive(PartialEq, Debug, Default)]
     struct TestTreeNode<T> {
         children: Vec<TestTreeNode<T>>,
         data: T,
@@ -1918,4 +3113,673 @@ mod tests {
             TreeNodeRecursion::Stop
         )
     );
+
+    #[derive(PartialEq, Debug, Clone)]
+    struct ConcreteTestNode {
+        children: Vec<ConcreteTestNode>,
+        data: String,
+    }
+
+    impl ConcreteTestNode {
+        fn new(children: Vec<Self>, data: &str) -> Self {
+            Self {
+                children,
+                data: data.to_string(),
+            }
+        }
+    }
+
+    impl ConcreteTreeNode for ConcreteTestNode {
+        fn children(&self) -> Vec<&Self> {
+            self.children.iter().collect()
+        }
+
+        fn take_children(self) -> (Self, Vec<Self>) {
+            let children = self.children;
+            (
+                Self {
+                    children: vec![],
+                    ..self
+                },
+                children,
+            )
+        }
+
+        fn with_new_children(mut self, children: Vec<Self>) -> Result<Self> {
+            self.children = children;
+            Ok(self)
+        }
+    }
+
+    fn concrete_test_tree() -> ConcreteTestNode {
+        ConcreteTestNode::new(
+            vec![
+                ConcreteTestNode::new(vec![], "b"),
+                ConcreteTestNode::new(vec![ConcreteTestNode::new(vec![], "d")], "c"),
+            ],
+            "a",
+        )
+    }
+
+    #[test]
+    fn test_apply_ref_visits_all_nodes_in_preorder() {
+        let tree = concrete_test_tree();
+        let mut visited = vec![];
+        tree.apply_ref(|n| {
+            visited.push(n.data.clone());
+            Ok(TreeNodeRecursion::Continue)
+        })
+        .unwrap();
+        assert_eq!(visited, vec!["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_find_ref_returns_reference_tied_to_root() {
+        let tree = concrete_test_tree();
+        let found = tree.find_ref(|n| n.data == "d");
+        assert_eq!(found.map(|n| n.data.as_str()), Some("d"));
+        assert!(tree.find_ref(|n| n.data == "z").is_none());
+    }
+
+    #[test]
+    fn test_visit_ref_runs_f_down_and_f_up() {
+        struct RecordingVisitor<'n> {
+            events: Vec<&'n str>,
+            up_events: Vec<&'n str>,
+        }
+
+        impl<'n> TreeNodeRefVisitor<'n> for RecordingVisitor<'n> {
+            type Node = ConcreteTestNode;
+
+            fn f_down(&mut self, node: &'n Self::Node) -> Result<TreeNodeRecursion> {
+                self.events.push(node.data.as_str());
+                Ok(TreeNodeRecursion::Continue)
+            }
+
+            fn f_up(&mut self, node: &'n Self::Node) -> Result<TreeNodeRecursion> {
+                self.up_events.push(node.data.as_str());
+                Ok(TreeNodeRecursion::Continue)
+            }
+        }
+
+        let tree = concrete_test_tree();
+        let mut visitor = RecordingVisitor {
+            events: vec![],
+            up_events: vec![],
+        };
+        tree.visit_ref(&mut visitor).unwrap();
+        assert_eq!(visitor.events, vec!["a", "b", "c", "d"]);
+        assert_eq!(visitor.up_events, vec!["b", "d", "c", "a"]);
+    }
+
+    #[test]
+    fn test_transform_down_with_payload_threads_depth_to_each_node() {
+        let tree = test_tree();
+        let result = tree
+            .transform_down_with_payload(
+                0usize,
+                &mut |mut node: TestTreeNode<String>, depth: usize| {
+                    let num_children = node.children.len();
+                    node.data = format!("{}@{depth}", node.data);
+                    Ok(Transformed::yes((node, vec![depth + 1; num_children])))
+                },
+            )
+            .unwrap();
+
+        // "j" is the root (depth 0); "i" is its only child (depth 1);
+        // "f" (depth 2) has two children, "e" and "g", both at depth 3.
+        assert_eq!(result.data.data, "j@0");
+        assert_eq!(result.data.children[0].data, "i@1");
+        assert_eq!(result.data.children[0].children[0].data, "f@2");
+        assert_eq!(result.data.children[0].children[0].children[0].data, "e@3");
+        assert_eq!(result.data.children[0].children[0].children[1].data, "g@3");
+    }
+
+    #[test]
+    fn test_transform_down_with_payload_errors_on_child_count_mismatch() {
+        let tree = test_tree();
+        let result = tree.transform_down_with_payload(
+            0usize,
+            &mut |node: TestTreeNode<String>, depth: usize| {
+                // "j" has exactly one child, but we hand back payloads for two.
+                Ok(Transformed::yes((node, vec![depth + 1, depth + 1])))
+            },
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_transform_up_with_payload_collects_child_counts() {
+        let tree = test_tree();
+        let result = tree
+            .transform_up_with_payload(&mut |node: TestTreeNode<String>, child_counts: Vec<usize>| {
+                let count = 1 + child_counts.iter().sum::<usize>();
+                Ok(Transformed::no((node, count)))
+            })
+            .unwrap();
+
+        // 10 nodes total: j, i, f, e, c, b, d, a, g, h.
+        assert_eq!(result.data.1, 10);
+    }
+
+    #[test]
+    fn test_transform_until_fixed_point_converges() {
+        let result = transform_until_fixed_point(0i32, 100, |n| {
+            if n >= 10 {
+                Ok(Transformed::no(n))
+            } else {
+                Ok(Transformed::yes(n + 1))
+            }
+        })
+        .unwrap();
+        assert_eq!(result, 10);
+    }
+
+    #[test]
+    fn test_transform_until_fixed_point_stops_on_cycle() {
+        // Oscillates between 0 and 1 forever; the cycle guard must prevent
+        // this from running away up to `max_iterations`.
+        let result = transform_until_fixed_point(0i32, 1000, |n| Ok(Transformed::yes(1 - n)));
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_transform_down_in_place_mutates_without_moving_out() {
+        let mut tree = test_tree();
+        let changed = tree
+            .transform_down_in_place(|mut n| {
+                n.data = format!("f_down({})", n.data);
+                Ok(Transformed::yes(n))
+            })
+            .unwrap();
+
+        assert!(changed);
+        assert_eq!(tree, transformed_down_tree());
+    }
+
+    #[test]
+    fn test_rewrite_in_place_matches_owned_rewrite() {
+        struct AppendRewriter;
+        impl TreeNodeRewriter for AppendRewriter {
+            type Node = TestTreeNode<String>;
+
+            fn f_down(&mut self, mut node: Self::Node) -> Result<Transformed<Self::Node>> {
+                node.data = format!("f_down({})", node.data);
+                Ok(Transformed::yes(node))
+            }
+
+            fn f_up(&mut self, mut node: Self::Node) -> Result<Transformed<Self::Node>> {
+                node.data = format!("f_up({})", node.data);
+                Ok(Transformed::yes(node))
+            }
+        }
+
+        let mut tree = test_tree();
+        let changed = tree.rewrite_in_place(&mut AppendRewriter).unwrap();
+
+        assert!(changed);
+        assert_eq!(tree, transformed_tree());
+    }
+
+    #[test]
+    fn test_rewrite_mut_matches_owned_rewrite() {
+        struct AppendMutator;
+        impl TreeNodeMutator for AppendMutator {
+            type Node = TestTreeNode<String>;
+
+            fn f_down(&mut self, node: &mut Self::Node) -> Result<Transformed<()>> {
+                node.data = format!("f_down({})", node.data);
+                Ok(Transformed::yes(()))
+            }
+
+            fn f_up(&mut self, node: &mut Self::Node) -> Result<Transformed<()>> {
+                node.data = format!("f_up({})", node.data);
+                Ok(Transformed::yes(()))
+            }
+        }
+
+        let mut tree = test_tree();
+        let result = tree.rewrite_mut(&mut AppendMutator).unwrap();
+
+        assert!(result.transformed);
+        assert_eq!(tree, transformed_tree());
+    }
+
+    #[test]
+    fn test_iter_preorder() {
+        let tree = concrete_test_tree();
+        let visited: Vec<_> = tree.iter_preorder().map(|n| n.data.as_str()).collect();
+        assert_eq!(visited, vec!["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_iter_postorder() {
+        let tree = concrete_test_tree();
+        let visited: Vec<_> = tree.iter_postorder().map(|n| n.data.as_str()).collect();
+        assert_eq!(visited, vec!["b", "d", "c", "a"]);
+    }
+
+    /// A node whose `Clone` impl increments a shared counter, so a test can
+    /// assert that a traversal never clones a node it only needs to inspect.
+    struct CloneCountingNode {
+        children: Vec<Self>,
+        data: &'static str,
+        clones: std::rc::Rc<std::cell::Cell<usize>>,
+    }
+
+    impl Clone for CloneCountingNode {
+        fn clone(&self) -> Self {
+            self.clones.set(self.clones.get() + 1);
+            Self {
+                children: self.children.clone(),
+                data: self.data,
+                clones: self.clones.clone(),
+            }
+        }
+    }
+
+    impl CloneCountingNode {
+        fn new(
+            children: Vec<Self>,
+            data: &'static str,
+            clones: std::rc::Rc<std::cell::Cell<usize>>,
+        ) -> Self {
+            Self {
+                children,
+                data,
+                clones,
+            }
+        }
+    }
+
+    impl ConcreteTreeNode for CloneCountingNode {
+        fn children(&self) -> Vec<&Self> {
+            self.children.iter().collect()
+        }
+
+        fn take_children(self) -> (Self, Vec<Self>) {
+            let children = self.children;
+            (
+                Self {
+                    children: vec![],
+                    ..self
+                },
+                children,
+            )
+        }
+
+        fn with_new_children(mut self, children: Vec<Self>) -> Result<Self> {
+            self.children = children;
+            Ok(self)
+        }
+    }
+
+    #[test]
+    fn test_apply_visits_children_by_borrow_without_cloning() {
+        let clones = std::rc::Rc::new(std::cell::Cell::new(0));
+        let tree = CloneCountingNode::new(
+            vec![
+                CloneCountingNode::new(vec![], "b", clones.clone()),
+                CloneCountingNode::new(vec![], "c", clones.clone()),
+            ],
+            "a",
+            clones.clone(),
+        );
+        // Constructing the tree above doesn't clone any node; only count
+        // clones that happen from here on, during the `apply` call itself.
+        clones.set(0);
+
+        let mut visited = vec![];
+        tree.apply(|n| {
+            visited.push(n.data);
+            Ok(TreeNodeRecursion::Continue)
+        })
+        .unwrap();
+
+        assert_eq!(visited, vec!["a", "b", "c"]);
+        assert_eq!(
+            clones.get(),
+            0,
+            "apply() must traverse children by reference, not by cloning them"
+        );
+    }
+
+    #[test]
+    fn test_apply_ref_harvests_references_tied_to_root_without_cloning() {
+        let clones = std::rc::Rc::new(std::cell::Cell::new(0));
+        let tree = CloneCountingNode::new(
+            vec![
+                CloneCountingNode::new(vec![], "b", clones.clone()),
+                CloneCountingNode::new(vec![], "c", clones.clone()),
+            ],
+            "a",
+            clones.clone(),
+        );
+        clones.set(0);
+
+        // Stash references gathered mid-traversal into a `Vec` that outlives
+        // the `apply_ref` call itself, rather than only using them inside
+        // the callback — this is the capability `apply`/`visit` can't offer
+        // because their closures receive a lifetime tied to each individual
+        // invocation, not to `tree`.
+        let mut harvested: Vec<&CloneCountingNode> = vec![];
+        tree.apply_ref(|n| {
+            harvested.push(n);
+            Ok(TreeNodeRecursion::Continue)
+        })
+        .unwrap();
+
+        let harvested_data: Vec<_> = harvested.iter().map(|n| n.data).collect();
+        assert_eq!(harvested_data, vec!["a", "b", "c"]);
+        assert_eq!(
+            clones.get(),
+            0,
+            "apply_ref() must hand back references, not clones, even when they outlive the callback"
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct DynTestNode {
+        children: Vec<Arc<DynTestNode>>,
+        data: String,
+    }
+
+    impl DynTestNode {
+        fn new(children: Vec<Arc<Self>>, data: &str) -> Arc<Self> {
+            Arc::new(Self {
+                children,
+                data: data.to_string(),
+            })
+        }
+    }
+
+    impl DynTreeNode for DynTestNode {
+        fn arc_children(&self) -> Vec<Arc<Self>> {
+            self.children.clone()
+        }
+
+        fn with_new_arc_children(self: Arc<Self>, new_children: Vec<Arc<Self>>) -> Result<Arc<Self>> {
+            Ok(Arc::new(Self {
+                children: new_children,
+                data: self.data.clone(),
+            }))
+        }
+
+        fn arc_children_mut(&mut self) -> &mut [Arc<Self>] {
+            &mut self.children
+        }
+    }
+
+    #[test]
+    fn test_rewrite_arc_in_place_preserves_identity_of_untouched_children() {
+        let b = DynTestNode::new(vec![], "b");
+        let c = DynTestNode::new(vec![], "c");
+        let mut root = DynTestNode::new(vec![b.clone(), c.clone()], "a");
+
+        struct AppendToB;
+        impl TreeNodeRewriter for AppendToB {
+            type Node = Arc<DynTestNode>;
+
+            fn f_up(&mut self, node: Self::Node) -> Result<Transformed<Self::Node>> {
+                if node.data == "b" {
+                    Ok(Transformed::yes(DynTestNode::new(vec![], "b!")))
+                } else {
+                    Ok(Transformed::no(node))
+                }
+            }
+        }
+
+        let transformed = rewrite_arc_in_place(&mut root, &mut AppendToB).unwrap();
+
+        assert!(transformed);
+        assert_eq!(root.data, "a");
+        assert_eq!(root.children[0].data, "b!");
+        // "c" was never touched by the rewrite, so it must still be the
+        // exact same `Arc` it started as.
+        assert!(Arc::ptr_eq(&root.children[1], &c));
+    }
+
+    #[test]
+    fn test_rewrite_arc_in_place_avoids_reallocating_unshared_ancestors() {
+        let leaf = DynTestNode::new(vec![], "leaf");
+        let mid = DynTestNode::new(vec![leaf], "mid");
+        // Keep an extra handle to "mid" so it's shared and can't be mutated
+        // through `Arc::get_mut` -- only "root" (uniquely owned here) can.
+        let mid_alias = mid.clone();
+        let mut root = DynTestNode::new(vec![mid], "root");
+        let root_ptr_before = Arc::as_ptr(&root);
+
+        struct AppendToLeaf;
+        impl TreeNodeRewriter for AppendToLeaf {
+            type Node = Arc<DynTestNode>;
+
+            fn f_up(&mut self, node: Self::Node) -> Result<Transformed<Self::Node>> {
+                if node.data == "leaf" {
+                    Ok(Transformed::yes(DynTestNode::new(vec![], "leaf!")))
+                } else {
+                    Ok(Transformed::no(node))
+                }
+            }
+        }
+
+        let transformed = rewrite_arc_in_place(&mut root, &mut AppendToLeaf).unwrap();
+
+        assert!(transformed);
+        assert_eq!(root.children[0].children[0].data, "leaf!");
+        // "root" was uniquely owned, so mutating a grandchild never required
+        // rebuilding "root"'s own allocation.
+        assert_eq!(Arc::as_ptr(&root), root_ptr_before);
+        // "mid" was shared (via `mid_alias`), so it had to be rebuilt to
+        // pick up the new child -- the old allocation `mid_alias` still
+        // points to is untouched by the rewrite.
+        assert_eq!(mid_alias.data, "mid");
+        assert_eq!(mid_alias.children[0].data, "leaf");
+    }
+
+    #[test]
+    fn test_fn_rewriter_matches_a_hand_written_rewriter() {
+        let tree = test_tree();
+        let changed = tree
+            .rewrite(&mut FnRewriter::new(
+                |mut node: TestTreeNode<String>| {
+                    node.data = format!("f_down({})", node.data);
+                    Ok(Transformed::yes(node))
+                },
+                |mut node: TestTreeNode<String>| {
+                    node.data = format!("f_up({})", node.data);
+                    Ok(Transformed::yes(node))
+                },
+            ))
+            .unwrap();
+
+        assert!(changed.transformed);
+        assert_eq!(changed.data, transformed_tree());
+    }
+
+    #[test]
+    fn test_map_until_stop_and_collect_indexed_passes_position_and_honors_stop() {
+        let items = vec![10, 20, 30, 40];
+        let mut seen_indices = vec![];
+        let result = items
+            .into_iter()
+            .map_until_stop_and_collect_indexed(|i, item| {
+                seen_indices.push(i);
+                if item == 30 {
+                    Ok(Transformed::new(item, true, TreeNodeRecursion::Stop))
+                } else {
+                    Ok(Transformed::yes(item + 1))
+                }
+            })
+            .unwrap();
+
+        // Stop on index 2 (item 30): later items are collected unchanged and
+        // `f` is never called on them again.
+        assert_eq!(seen_indices, vec![0, 1, 2]);
+        assert_eq!(result.data, vec![11, 21, 30, 40]);
+        assert!(result.transformed);
+        assert_eq!(result.tnr, TreeNodeRecursion::Stop);
+    }
+
+    struct MacroGeneratedNode {
+        children: Vec<Self>,
+        data: i32,
+    }
+    impl_concrete_tree_node_for_vec_children!(MacroGeneratedNode);
+
+    #[test]
+    fn test_impl_concrete_tree_node_for_vec_children_macro() {
+        let tree = MacroGeneratedNode {
+            children: vec![
+                MacroGeneratedNode {
+                    children: vec![],
+                    data: 2,
+                },
+                MacroGeneratedNode {
+                    children: vec![],
+                    data: 3,
+                },
+            ],
+            data: 1,
+        };
+
+        let result = tree
+            .map_children(|mut n| {
+                n.data *= 10;
+                Ok(Transformed::yes(n))
+            })
+            .unwrap();
+
+        assert!(result.transformed);
+        assert_eq!(result.data.data, 1);
+        assert_eq!(result.data.children[0].data, 20);
+        assert_eq!(result.data.children[1].data, 30);
+    }
+
+    fn append_bang(mut n: ConcreteTestNode) -> Result<Transformed<ConcreteTestNode>> {
+        n.data = format!("{}!", n.data);
+        Ok(Transformed::yes(n))
+    }
+
+    #[test]
+    fn test_transform_down_iter_matches_recursive_transform_down() {
+        let recursive = concrete_test_tree().transform_down(append_bang).unwrap();
+        let iterative = concrete_test_tree()
+            .transform_down_iter(&mut append_bang)
+            .unwrap();
+
+        assert_eq!(iterative.data, recursive.data);
+        assert_eq!(iterative.transformed, recursive.transformed);
+        assert_eq!(iterative.tnr, recursive.tnr);
+        assert_eq!(iterative.data.data, "a!");
+        assert_eq!(iterative.data.children[0].data, "b!");
+        assert_eq!(iterative.data.children[1].data, "c!");
+        assert_eq!(iterative.data.children[1].children[0].data, "d!");
+    }
+
+    #[test]
+    fn test_transform_down_iter_stop_halts_remaining_nodes() {
+        let tree = concrete_test_tree();
+        let mut visited = vec![];
+        let result = tree
+            .transform_down_iter(&mut |mut n: ConcreteTestNode| {
+                visited.push(n.data.clone());
+                if n.data == "b" {
+                    n.data = "b!".to_string();
+                    Ok(Transformed::new(n, true, TreeNodeRecursion::Stop))
+                } else {
+                    n.data = format!("{}!", n.data);
+                    Ok(Transformed::yes(n))
+                }
+            })
+            .unwrap();
+
+        // Pre-order: "a" then "b", which stops the walk; "c"/"d" are never
+        // visited at all, matching the recursive implementation.
+        assert_eq!(visited, vec!["a", "b"]);
+        assert_eq!(result.tnr, TreeNodeRecursion::Stop);
+        assert_eq!(result.data.data, "a!");
+        assert_eq!(result.data.children[0].data, "b!");
+        assert_eq!(result.data.children[1].data, "c");
+        assert_eq!(result.data.children[1].children[0].data, "d");
+    }
+
+    #[test]
+    fn test_transform_up_iter_matches_recursive_transform_up() {
+        let recursive = concrete_test_tree().transform_up(append_bang).unwrap();
+        let iterative = concrete_test_tree()
+            .transform_up_iter(&mut append_bang)
+            .unwrap();
+
+        assert_eq!(iterative.data, recursive.data);
+        assert_eq!(iterative.transformed, recursive.transformed);
+        assert_eq!(iterative.tnr, recursive.tnr);
+        assert_eq!(iterative.data.data, "a!");
+        assert_eq!(iterative.data.children[0].data, "b!");
+        assert_eq!(iterative.data.children[1].data, "c!");
+        assert_eq!(iterative.data.children[1].children[0].data, "d!");
+    }
+
+    #[test]
+    fn test_transform_up_iter_stop_halts_remaining_nodes() {
+        let tree = concrete_test_tree();
+        let mut visited = vec![];
+        let result = tree
+            .transform_up_iter(&mut |mut n: ConcreteTestNode| {
+                visited.push(n.data.clone());
+                if n.data == "d" {
+                    Ok(Transformed::new(n, false, TreeNodeRecursion::Stop))
+                } else {
+                    n.data = format!("{}!", n.data);
+                    Ok(Transformed::yes(n))
+                }
+            })
+            .unwrap();
+
+        // Post-order: "b" then "d" (the first leaves reached), which stops
+        // the walk; "c" and "a" are never passed to `f` at all.
+        assert_eq!(visited, vec!["b", "d"]);
+        assert_eq!(result.tnr, TreeNodeRecursion::Stop);
+        assert_eq!(result.data.data, "a");
+        assert_eq!(result.data.children[0].data, "b!");
+        assert_eq!(result.data.children[1].children[0].data, "d");
+    }
+
+    #[test]
+    fn test_rewrite_traced_records_jump_and_renders_as_a_tree() {
+        struct JumpOnC;
+        impl TreeNodeRewriter for JumpOnC {
+            type Node = TestTreeNode<String>;
+
+            fn f_down(&mut self, mut node: Self::Node) -> Result<Transformed<Self::Node>> {
+                if node.data == "c" {
+                    node.data = format!("{}*", node.data);
+                    Ok(Transformed::new(node, true, TreeNodeRecursion::Jump))
+                } else {
+                    Ok(Transformed::no(node))
+                }
+            }
+        }
+
+        let (result, trace) =
+            rewrite_traced(test_tree(), &|n| n.data.clone(), &mut JumpOnC).unwrap();
+
+        // j -> i -> f -> e -> c
+        let c_node = &result.data.children[0].children[0].children[0].children[0];
+        assert!(result.transformed);
+        // "c" jumped in f_down, so its children ("b", "d") are never visited
+        // and are kept unchanged.
+        assert_eq!(c_node.data, "c*");
+        assert_eq!(c_node.children.len(), 2);
+        assert_eq!(c_node.children[0].data, "b");
+        assert_eq!(c_node.children[1].data, "d");
+
+        let c_trace = &trace.root.children[0].children[0].children[0].children[0];
+        assert_eq!(c_trace.label, "c");
+        assert_eq!(c_trace.f_down, TreeNodeRecursion::Jump);
+        assert!(c_trace.children.is_empty());
+
+        let rendered = trace.to_string();
+        assert!(rendered.contains("└── c [down: Jump, transformed] [up: Continue]"));
+        assert!(rendered.contains("├── "));
+    }
 }
@@ -0,0 +1,35 @@
+//! A pluggable catalog provider, so a fully-qualified [`Identifier`] can
+//! resolve against something other than the in-process meta-catalog that
+//! `GLOBAL_DAFT_META_CATALOG` already manages for views and registered
+//! logical plans -- an Iceberg REST catalog ([`crate::iceberg_rest`]), for
+//! instance.
+//!
+//! This crate's checked-out slice doesn't carry `common_scan_info`'s
+//! `PhysicalScanInfo` or `daft_logical_plan`'s `SourceInfo`/
+//! `LogicalPlanBuilder`, so a `Catalog` implementation's `load_table` stops
+//! at the catalog's own raw response (see [`crate::iceberg_rest::IcebergLoadedTable`])
+//! rather than building one of those values itself; the scan planner that
+//! does have those types is what turns a loaded table into a
+//! `SourceInfo::Physical` for `read_table` to stream from.
+
+use common_error::DaftResult;
+
+use crate::identifier::Identifier;
+
+/// A named provider of tables, addressed by [`Identifier`].
+pub trait Catalog: Send + Sync {
+    /// The name this catalog is (or will be) registered under.
+    fn name(&self) -> &str;
+
+    /// Resolves `ident` against this catalog, returning a serde_json-backed
+    /// description of the table's current metadata.
+    fn load_table(&self, ident: &Identifier)
+        -> DaftResult<crate::iceberg_rest::IcebergLoadedTable>;
+
+    /// Registers an existing table's metadata file with this catalog.
+    fn register_table(
+        &self,
+        ident: &Identifier,
+        metadata_location: &str,
+    ) -> DaftResult<crate::iceberg_rest::IcebergLoadedTable>;
+}
@@ -0,0 +1,236 @@
+//! A [`Catalog`] provider backed by an Iceberg REST catalog server.
+//!
+//! This talks to any server implementing the Iceberg REST Catalog spec
+//! (<https://iceberg.apache.org/spec/#rest-catalog>): [`IcebergRestCatalog::connect`]
+//! resolves the server's effective configuration via `GET /v1/config` before
+//! issuing any other request, and [`IcebergRestCatalog`] otherwise resolves a
+//! fully-qualified [`Identifier`] the same way Daft's other catalog providers
+//! do, through the [`Catalog`] trait.
+
+use std::collections::HashMap;
+
+use common_error::{DaftError, DaftResult};
+use serde::Deserialize;
+
+use crate::{catalog::Catalog, identifier::Identifier};
+
+/// Connection details for an Iceberg REST catalog.
+#[derive(Debug, Clone)]
+pub struct IcebergRestCatalogConfig {
+    /// Base URL of the REST catalog server, e.g. `http://localhost:8181`.
+    pub uri: String,
+    /// Optional bearer token used to authenticate requests.
+    pub token: Option<String>,
+    /// Catalog-level warehouse location, if required by the server.
+    pub warehouse: Option<String>,
+}
+
+/// A table identifier as addressed by the REST catalog: a namespace (one or
+/// more parts) plus a table name.
+#[derive(Debug, Clone)]
+pub struct IcebergTableIdent {
+    pub namespace: Vec<String>,
+    pub name: String,
+}
+
+impl IcebergTableIdent {
+    /// Parses a dotted identifier such as `"db.schema.table"` into a
+    /// namespace + name pair.
+    pub fn parse(identifier: &str) -> DaftResult<Self> {
+        let mut parts: Vec<String> = identifier.split('.').map(str::to_string).collect();
+        let name = parts.pop().ok_or_else(|| {
+            DaftError::ValueError(format!("Invalid Iceberg table identifier: {identifier}"))
+        })?;
+        Ok(Self {
+            namespace: parts,
+            name,
+        })
+    }
+
+    /// The REST catalog wire format for a namespace: its parts joined with
+    /// the unit separator byte (0x1F), per the Iceberg REST spec.
+    fn namespace_path_segment(&self) -> String {
+        self.namespace.join("\u{1f}")
+    }
+}
+
+impl From<&Identifier> for IcebergTableIdent {
+    fn from(ident: &Identifier) -> Self {
+        Self {
+            namespace: ident.namespace.clone(),
+            name: ident.name.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct LoadTableResponse {
+    #[serde(rename = "metadata-location")]
+    metadata_location: Option<String>,
+    metadata: serde_json::Value,
+}
+
+/// A loaded Iceberg table's REST catalog response: the resolved metadata
+/// location (if the server tracks one) and the raw table metadata JSON,
+/// which the Iceberg scan/write planners parse into Daft's logical schema.
+#[derive(Debug, Clone)]
+pub struct IcebergLoadedTable {
+    pub metadata_location: Option<String>,
+    pub metadata: serde_json::Value,
+}
+
+/// The response body of the Iceberg REST spec's `GET /v1/config` endpoint:
+/// server-mandated `overrides` take precedence over any client-supplied
+/// setting, while `defaults` only fill in settings the client didn't supply.
+#[derive(Debug, Deserialize, Default)]
+struct CatalogConfigResponse {
+    #[serde(default)]
+    overrides: HashMap<String, String>,
+    #[serde(default)]
+    defaults: HashMap<String, String>,
+}
+
+/// A client for an Iceberg REST catalog, providing the `read_table` /
+/// `register_table` primitives that the catalog module dispatches to.
+pub struct IcebergRestCatalog {
+    name: String,
+    config: IcebergRestCatalogConfig,
+    client: reqwest::blocking::Client,
+}
+
+impl IcebergRestCatalog {
+    /// Connects to the REST catalog at `config.uri`, calling `GET /v1/config`
+    /// first -- per the Iceberg REST spec, this is how a client discovers the
+    /// server's required/default properties (e.g. a server-mandated
+    /// `warehouse`) before issuing any other request.
+    pub fn connect(name: impl Into<String>, config: IcebergRestCatalogConfig) -> DaftResult<Self> {
+        let client = reqwest::blocking::Client::new();
+        let mut catalog = Self {
+            name: name.into(),
+            config,
+            client,
+        };
+        let server_config = catalog.fetch_config()?;
+        for (key, value) in server_config.defaults {
+            if key == "warehouse" && catalog.config.warehouse.is_none() {
+                catalog.config.warehouse = Some(value);
+            }
+        }
+        for (key, value) in server_config.overrides {
+            if key == "warehouse" {
+                catalog.config.warehouse = Some(value);
+            }
+        }
+        Ok(catalog)
+    }
+
+    /// Calls `GET /v1/config`, optionally scoped to a `warehouse`, to
+    /// resolve the server's effective catalog configuration.
+    fn fetch_config(&self) -> DaftResult<CatalogConfigResponse> {
+        let mut req = self.request(reqwest::Method::GET, "config");
+        if let Some(warehouse) = &self.config.warehouse {
+            req = req.query(&[("warehouse", warehouse)]);
+        }
+        let response = req.send().map_err(|e| DaftError::External(e.into()))?;
+        if !response.status().is_success() {
+            return Err(DaftError::ValueError(format!(
+                "Iceberg REST catalog returned {} while fetching /v1/config",
+                response.status(),
+            )));
+        }
+        response.json().map_err(|e| DaftError::External(e.into()))
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::blocking::RequestBuilder {
+        let url = format!("{}/v1/{}", self.config.uri.trim_end_matches('/'), path);
+        let mut req = self.client.request(method, url);
+        if let Some(token) = &self.config.token {
+            req = req.bearer_auth(token);
+        }
+        req
+    }
+
+    /// Loads an Iceberg table's metadata via `GET
+    /// /v1/namespaces/{namespace}/tables/{table}`, the entry point used to
+    /// resolve a table before Daft can plan a read over it.
+    pub fn load_table(&self, ident: &IcebergTableIdent) -> DaftResult<IcebergLoadedTable> {
+        let path = format!(
+            "namespaces/{}/tables/{}",
+            ident.namespace_path_segment(),
+            ident.name
+        );
+        let response = self
+            .request(reqwest::Method::GET, &path)
+            .send()
+            .map_err(|e| DaftError::External(e.into()))?;
+
+        if !response.status().is_success() {
+            return Err(DaftError::ValueError(format!(
+                "Iceberg REST catalog returned {} while loading table {}.{}",
+                response.status(),
+                ident.namespace.join("."),
+                ident.name
+            )));
+        }
+
+        let body: LoadTableResponse = response.json().map_err(|e| DaftError::External(e.into()))?;
+
+        Ok(IcebergLoadedTable {
+            metadata_location: body.metadata_location,
+            metadata: body.metadata,
+        })
+    }
+
+    /// Registers an existing table's metadata file with the catalog via
+    /// `POST /v1/namespaces/{namespace}/register`, the REST spec's mechanism
+    /// for adding a table that already has on-disk Iceberg metadata.
+    pub fn register_table(
+        &self,
+        ident: &IcebergTableIdent,
+        metadata_location: &str,
+    ) -> DaftResult<IcebergLoadedTable> {
+        let path = format!("namespaces/{}/register", ident.namespace_path_segment());
+        let response = self
+            .request(reqwest::Method::POST, &path)
+            .json(&serde_json::json!({
+                "name": ident.name,
+                "metadata-location": metadata_location,
+            }))
+            .send()
+            .map_err(|e| DaftError::External(e.into()))?;
+
+        if !response.status().is_success() {
+            return Err(DaftError::ValueError(format!(
+                "Iceberg REST catalog returned {} while registering table {}.{}",
+                response.status(),
+                ident.namespace.join("."),
+                ident.name
+            )));
+        }
+
+        let body: LoadTableResponse = response.json().map_err(|e| DaftError::External(e.into()))?;
+
+        Ok(IcebergLoadedTable {
+            metadata_location: body.metadata_location,
+            metadata: body.metadata,
+        })
+    }
+}
+
+impl Catalog for IcebergRestCatalog {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn load_table(&self, ident: &Identifier) -> DaftResult<IcebergLoadedTable> {
+        self.load_table(&IcebergTableIdent::from(ident))
+    }
+
+    fn register_table(
+        &self,
+        ident: &Identifier,
+        metadata_location: &str,
+    ) -> DaftResult<IcebergLoadedTable> {
+        self.register_table(&IcebergTableIdent::from(ident), metadata_location)
+    }
+}
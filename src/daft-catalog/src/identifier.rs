@@ -0,0 +1,51 @@
+use std::fmt::{Display, Formatter};
+
+use common_error::{DaftError, DaftResult};
+
+/// A fully-qualified reference to a catalog object: zero or more namespace
+/// parts followed by a final name, e.g. `my_catalog.my_schema.my_table`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Identifier {
+    pub namespace: Vec<String>,
+    pub name: String,
+}
+
+impl Identifier {
+    pub fn new(namespace: Vec<String>, name: String) -> Self {
+        Self { namespace, name }
+    }
+
+    /// Parses a dotted SQL identifier such as `"db.schema.table"` into its
+    /// namespace parts and final name. When `normalize` is set, each part is
+    /// lowercased, matching SQL's case-insensitive-unless-quoted identifier
+    /// convention.
+    pub fn from_sql(input: &str, normalize: bool) -> DaftResult<Self> {
+        let mut parts: Vec<String> = input
+            .split('.')
+            .map(|part| {
+                if normalize {
+                    part.to_lowercase()
+                } else {
+                    part.to_string()
+                }
+            })
+            .collect();
+        let name = parts
+            .pop()
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| DaftError::ValueError(format!("Invalid identifier: {input}")))?;
+        Ok(Self {
+            namespace: parts,
+            name,
+        })
+    }
+}
+
+impl Display for Identifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for part in &self.namespace {
+            write!(f, "{part}.")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
@@ -0,0 +1,221 @@
+use common_error::DaftResult;
+use num_traits::ToPrimitive;
+
+use super::as_arrow::AsArrow;
+use crate::{
+    array::DataArray,
+    datatypes::{
+        DaftNumericType, Decimal128Array, Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type,
+        UInt32Type, UInt64Type, UInt8Type, Utf8Array,
+    },
+    prelude::BinaryArray,
+};
+
+/// 32-bit murmur3 (x86 variant), as specified by the Iceberg bucket transform.
+/// <https://iceberg.apache.org/spec/#appendix-b-32-bit-hash-requirements>
+fn murmur3_x86_32(data: &[u8]) -> i32 {
+    const C1: u32 = 0xcc9e_2d51;
+    const C2: u32 = 0x1b87_3593;
+    const SEED: u32 = 0;
+
+    let mut h1 = SEED;
+
+    let chunks = data.chunks_exact(4);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        let mut k1 = u32::from_le_bytes(chunk.try_into().unwrap());
+        k1 = k1.wrapping_mul(C1);
+        k1 = k1.rotate_left(15);
+        k1 = k1.wrapping_mul(C2);
+
+        h1 ^= k1;
+        h1 = h1.rotate_left(13);
+        h1 = h1.wrapping_mul(5).wrapping_add(0xe654_6b64);
+    }
+
+    let mut k1: u32 = 0;
+    for (i, b) in remainder.iter().enumerate() {
+        k1 ^= u32::from(*b) << (8 * i);
+    }
+    if !remainder.is_empty() {
+        k1 = k1.wrapping_mul(C1);
+        k1 = k1.rotate_left(15);
+        k1 = k1.wrapping_mul(C2);
+        h1 ^= k1;
+    }
+
+    h1 ^= data.len() as u32;
+    h1 ^= h1 >> 16;
+    h1 = h1.wrapping_mul(0x85eb_ca6b);
+    h1 ^= h1 >> 13;
+    h1 = h1.wrapping_mul(0xc2b2_ae35);
+    h1 ^= h1 >> 16;
+
+    h1 as i32
+}
+
+/// Hashes the little-endian 8-byte signed-long serialization of `v`, per the
+/// spec's rules for ints, longs, dates, and timestamps.
+fn hash_long(v: i64) -> i32 {
+    murmur3_x86_32(&v.to_le_bytes())
+}
+
+fn bucket_from_hash(hash: i32, n: i32) -> i32 {
+    (hash & 0x7fff_ffff) % n
+}
+
+macro_rules! impl_int_iceberg_bucket {
+    ($DT:ty) => {
+        impl DataArray<$DT> {
+            pub fn iceberg_bucket(&self, n: i32) -> DaftResult<DataArray<Int32Type>> {
+                let as_arrowed = self.as_arrow();
+                let bucketed = as_arrowed.into_iter().map(|v| {
+                    v.map(|v| {
+                        // `to_i64` fails for a `u64` above `i64::MAX`, which
+                        // is a legitimate value for this type (reached by
+                        // `UInt64Type`) -- fall back to reinterpreting its
+                        // native 8-byte pattern as `i64` rather than
+                        // rejecting a valid input.
+                        let i = v
+                            .to_i64()
+                            .unwrap_or_else(|| v.to_u64().unwrap() as i64);
+                        bucket_from_hash(hash_long(i), n)
+                    })
+                });
+                let array = Box::new(arrow2::array::PrimitiveArray::from_iter(bucketed));
+                Ok(DataArray::<Int32Type>::from((self.name(), array)))
+            }
+        }
+    };
+}
+
+impl_int_iceberg_bucket!(Int8Type);
+impl_int_iceberg_bucket!(Int16Type);
+impl_int_iceberg_bucket!(Int32Type);
+impl_int_iceberg_bucket!(Int64Type);
+
+impl_int_iceberg_bucket!(UInt8Type);
+impl_int_iceberg_bucket!(UInt16Type);
+impl_int_iceberg_bucket!(UInt32Type);
+impl_int_iceberg_bucket!(UInt64Type);
+
+impl Decimal128Array {
+    pub fn iceberg_bucket(&self, n: i32) -> DaftResult<DataArray<Int32Type>> {
+        let as_arrow = self.as_arrow();
+        let bucketed = as_arrow.into_iter().map(|v| {
+            v.map(|i| {
+                let bytes = minimal_twos_complement_be_bytes(*i);
+                bucket_from_hash(murmur3_x86_32(&bytes), n)
+            })
+        });
+        let array = Box::new(arrow2::array::PrimitiveArray::from_iter(bucketed));
+        Ok(DataArray::<Int32Type>::from((self.name(), array)))
+    }
+}
+
+impl Utf8Array {
+    pub fn iceberg_bucket(&self, n: i32) -> DaftResult<DataArray<Int32Type>> {
+        let as_arrow = self.as_arrow();
+        let bucketed = as_arrow
+            .into_iter()
+            .map(|v| v.map(|s| bucket_from_hash(murmur3_x86_32(s.as_bytes()), n)));
+        let array = Box::new(arrow2::array::PrimitiveArray::from_iter(bucketed));
+        Ok(DataArray::<Int32Type>::from((self.name(), array)))
+    }
+}
+
+impl BinaryArray {
+    pub fn iceberg_bucket(&self, n: i32) -> DaftResult<DataArray<Int32Type>> {
+        let as_arrow = self.as_arrow();
+        let bucketed = as_arrow
+            .into_iter()
+            .map(|v| v.map(|b| bucket_from_hash(murmur3_x86_32(b), n)));
+        let array = Box::new(arrow2::array::PrimitiveArray::from_iter(bucketed));
+        Ok(DataArray::<Int32Type>::from((self.name(), array)))
+    }
+}
+
+/// The minimal big-endian two's-complement byte representation of `v`, as
+/// required for hashing decimal unscaled values.
+fn minimal_twos_complement_be_bytes(v: i128) -> Vec<u8> {
+    let full = v.to_be_bytes();
+    let is_negative = v < 0;
+    let pad_byte = if is_negative { 0xffu8 } else { 0x00u8 };
+
+    let mut start = 0;
+    while start < full.len() - 1
+        && full[start] == pad_byte
+        && (full[start + 1] & 0x80 == 0x80) == is_negative
+    {
+        start += 1;
+    }
+    full[start..].to_vec()
+}
+
+// Epoch offsets for the temporal transforms are computed directly from the
+// day-since-epoch (Date) or micros-since-epoch (Timestamp) representations,
+// matching the Iceberg spec's `year`/`month`/`day`/`hour` partition transforms.
+const DAYS_PER_YEAR_GROUP: i64 = 146_097; // days in 400 Gregorian years, used for floor-division of negative years
+
+impl DataArray<Int32Type> {
+    /// Iceberg `day` transform for a `Date` array: days since 1970-01-01.
+    pub fn iceberg_day(&self) -> DaftResult<DataArray<Int32Type>> {
+        let as_arrowed = self.as_arrow();
+        let days = as_arrowed.into_iter().map(|v| v.copied());
+        let array = Box::new(arrow2::array::PrimitiveArray::from_iter(days));
+        Ok(DataArray::<Int32Type>::from((self.name(), array)))
+    }
+
+    /// Iceberg `year` transform for a `Date` array: years since 1970.
+    pub fn iceberg_year(&self) -> DaftResult<DataArray<Int32Type>> {
+        let as_arrowed = self.as_arrow();
+        let years = as_arrowed
+            .into_iter()
+            .map(|v| v.map(|d| days_to_ymd(*d as i64).0 - 1970));
+        let array = Box::new(arrow2::array::PrimitiveArray::from_iter(years));
+        Ok(DataArray::<Int32Type>::from((self.name(), array)))
+    }
+
+    /// Iceberg `month` transform for a `Date` array: `year * 12 + month` months since 1970.
+    pub fn iceberg_month(&self) -> DaftResult<DataArray<Int32Type>> {
+        let as_arrowed = self.as_arrow();
+        let months = as_arrowed.into_iter().map(|v| {
+            v.map(|d| {
+                let (year, month, _) = days_to_ymd(*d as i64);
+                (year - 1970) * 12 + (month - 1)
+            })
+        });
+        let array = Box::new(arrow2::array::PrimitiveArray::from_iter(months));
+        Ok(DataArray::<Int32Type>::from((self.name(), array)))
+    }
+}
+
+impl DataArray<Int64Type> {
+    /// Iceberg `hour` transform for a `Timestamp(Microseconds)` array: hours since epoch.
+    pub fn iceberg_hour(&self) -> DaftResult<DataArray<Int32Type>> {
+        let as_arrowed = self.as_arrow();
+        let hours = as_arrowed
+            .into_iter()
+            .map(|v| v.map(|micros| (micros.div_euclid(1_000_000 * 3_600)) as i32));
+        let array = Box::new(arrow2::array::PrimitiveArray::from_iter(hours));
+        Ok(DataArray::<Int32Type>::from((self.name(), array)))
+    }
+}
+
+/// Converts days since the Unix epoch (1970-01-01) to a proleptic Gregorian
+/// `(year, month, day)` triple. Used to derive the `year`/`month` Iceberg
+/// partition transforms without pulling in a full calendar library.
+fn days_to_ymd(days_since_epoch: i64) -> (i64, i64, i64) {
+    // Shift so day 0 is 0000-03-01, which keeps leap-day arithmetic simple.
+    let z = days_since_epoch + 719_468;
+    let era = if z >= 0 { z } else { z - DAYS_PER_YEAR_GROUP + 1 } / DAYS_PER_YEAR_GROUP;
+    let doe = z - era * DAYS_PER_YEAR_GROUP; // [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365; // [0, 399]
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+    let mp = (5 * doy + 2) / 153; // [0, 11]
+    let d = doy - (153 * mp + 2) / 5 + 1; // [1, 31]
+    let m = if mp < 10 { mp + 3 } else { mp - 9 }; // [1, 12]
+    let y = if m <= 2 { y + 1 } else { y };
+    (y, m, d)
+}
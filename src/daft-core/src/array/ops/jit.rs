@@ -0,0 +1,383 @@
+//! A small fused-elementwise-float kernel IR, plus an interpreter that
+//! evaluates it directly over `Float64` `DataArray` buffers.
+//!
+//! A chained expression like `sqrt(a*a + b*b)` otherwise allocates and scans
+//! a full array per operation (each of `mul`, `mul`, `add`, `sqrt` running
+//! its own per-element `apply` pass independently). [`FloatKernelExpr`]
+//! lets the expression layer lower such a tree into one IR, then evaluate it
+//! in a single pass with [`eval_fused`] — via a JIT-compiled kernel when the
+//! `jit` feature is enabled and the IR is fully supported, falling back to
+//! [`eval_interpreted`] otherwise (unsupported node, feature disabled, or a
+//! JIT compilation failure).
+
+use common_error::DaftResult;
+
+use crate::{array::ops::as_arrow::AsArrow, datatypes::Float64Array};
+
+/// A node in a fused elementwise float expression tree. `Column` indexes
+/// into the `columns` slice passed to [`eval_fused`]/[`eval_interpreted`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum FloatKernelExpr {
+    Column(usize),
+    Literal(f64),
+    Add(Box<Self>, Box<Self>),
+    Sub(Box<Self>, Box<Self>),
+    Mul(Box<Self>, Box<Self>),
+    Div(Box<Self>, Box<Self>),
+    Sqrt(Box<Self>),
+    Abs(Box<Self>),
+    Ln(Box<Self>),
+}
+
+impl FloatKernelExpr {
+    /// A canonical string key for this IR shape, used to cache compiled
+    /// kernels keyed by shape rather than by the expression's identity.
+    fn shape_key(&self) -> String {
+        format!("{self:?}")
+    }
+
+    fn eval_at(&self, columns: &[&[f64]], row: usize) -> f64 {
+        match self {
+            Self::Column(idx) => columns[*idx][row],
+            Self::Literal(v) => *v,
+            Self::Add(l, r) => l.eval_at(columns, row) + r.eval_at(columns, row),
+            Self::Sub(l, r) => l.eval_at(columns, row) - r.eval_at(columns, row),
+            Self::Mul(l, r) => l.eval_at(columns, row) * r.eval_at(columns, row),
+            Self::Div(l, r) => l.eval_at(columns, row) / r.eval_at(columns, row),
+            Self::Sqrt(v) => v.eval_at(columns, row).sqrt(),
+            Self::Abs(v) => v.eval_at(columns, row).abs(),
+            Self::Ln(v) => v.eval_at(columns, row).ln(),
+        }
+    }
+}
+
+/// Evaluates `expr` row-by-row over `columns` using the plain interpreter
+/// (the non-JIT `apply`-equivalent path). Always available, and used as the
+/// fallback when the `jit` feature is disabled or `expr` isn't supported by
+/// the compiler.
+fn eval_interpreted(
+    expr: &FloatKernelExpr,
+    columns: &[&Float64Array],
+) -> DaftResult<Float64Array> {
+    let len = columns.first().map_or(0, |c| c.len());
+    if len == 0 {
+        return Ok(Float64Array::from((
+            "fused",
+            Box::new(arrow2::array::PrimitiveArray::<f64>::from_iter(
+                std::iter::empty::<Option<f64>>(),
+            )),
+        )));
+    }
+
+    let buffers: Vec<&[f64]> = columns
+        .iter()
+        .map(|c| c.as_arrow().values().as_slice())
+        .collect();
+    let combined_validity = combined_validity(columns);
+    let values = (0..len).map(|row| {
+        let valid = combined_validity
+            .as_ref()
+            .map_or(true, |validity| validity.get_bit(row));
+        valid.then(|| expr.eval_at(&buffers, row))
+    });
+
+    Ok(Float64Array::from((
+        "fused",
+        Box::new(arrow2::array::PrimitiveArray::<f64>::from_iter(values)),
+    )))
+}
+
+/// Intersects the validity bitmaps of `columns`; `None` means every input is
+/// all-valid (no bitmap attached), equivalent to an all-true bitmap.
+fn combined_validity(columns: &[&Float64Array]) -> Option<arrow2::bitmap::Bitmap> {
+    columns
+        .iter()
+        .filter_map(|c| c.as_arrow().validity())
+        .fold(None, |acc: Option<arrow2::bitmap::Bitmap>, v| {
+            Some(match acc {
+                Some(acc) => &acc & v,
+                None => v.clone(),
+            })
+        })
+}
+
+/// Evaluates a fused elementwise float expression tree over `columns` in a
+/// single pass, respecting each input's validity bitmap (the output is null
+/// wherever any input was null) and returning an empty array without
+/// invoking a kernel when `columns` are empty.
+///
+/// With the `jit` feature enabled, this compiles `expr` to a cached,
+/// JIT-compiled kernel the first time a given IR shape is seen and reuses
+/// it on every subsequent call with that shape; numeric results are
+/// bit-identical to [`eval_interpreted`] for every supported node. Without
+/// the feature (or for an IR shape the compiler doesn't support), this is
+/// exactly [`eval_interpreted`].
+pub fn eval_fused(
+    expr: &FloatKernelExpr,
+    columns: &[&Float64Array],
+) -> DaftResult<Float64Array> {
+    #[cfg(feature = "jit")]
+    {
+        if let Some(result) = compiler::try_eval_jit(expr, columns)? {
+            return Ok(result);
+        }
+    }
+
+    eval_interpreted(expr, columns)
+}
+
+#[cfg(feature = "jit")]
+mod compiler {
+    //! Cranelift-backed JIT compilation of [`FloatKernelExpr`] into a
+    //! function `(ptrs: *const *const f64, len: usize, out: *mut f64)`,
+    //! cached by [`FloatKernelExpr::shape_key`] so repeated evaluation of
+    //! the same fused shape (e.g. across partitions) reuses the generated
+    //! machine code instead of recompiling it.
+
+    use std::{
+        collections::HashMap,
+        sync::{Mutex, OnceLock},
+    };
+
+    use cranelift_codegen::ir::{types, AbiParam, InstBuilder};
+    use cranelift_frontend::{FunctionBuilder, FunctionBuilderContext};
+    use cranelift_jit::{JITBuilder, JITModule};
+    use cranelift_module::{Linkage, Module};
+
+    use super::{combined_validity, FloatKernelExpr};
+    use crate::{array::ops::as_arrow::AsArrow, datatypes::Float64Array};
+    use common_error::{DaftError, DaftResult};
+
+    type KernelFn = unsafe extern "C" fn(*const *const f64, usize, *mut f64);
+
+    struct CompiledKernel {
+        // Kept alive for as long as `func` may be called; the JIT-mapped
+        // code is only valid while its owning module is alive.
+        _module: JITModule,
+        func: KernelFn,
+    }
+
+    // Safety: the compiled function only reads/writes through its explicit
+    // pointer arguments and touches no shared mutable state, so it's sound
+    // to call concurrently from multiple threads once compiled.
+    unsafe impl Send for CompiledKernel {}
+    unsafe impl Sync for CompiledKernel {}
+
+    fn kernel_cache() -> &'static Mutex<HashMap<String, Option<std::sync::Arc<CompiledKernel>>>> {
+        static CACHE: OnceLock<Mutex<HashMap<String, Option<std::sync::Arc<CompiledKernel>>>>> =
+            OnceLock::new();
+        CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+    }
+
+    /// Returns `Ok(Some(result))` if `expr` was evaluated via a JIT kernel,
+    /// `Ok(None)` if the IR (or a prior compilation attempt for this shape)
+    /// isn't supported and the caller should fall back to the interpreter.
+    pub(super) fn try_eval_jit(
+        expr: &FloatKernelExpr,
+        columns: &[&Float64Array],
+    ) -> DaftResult<Option<Float64Array>> {
+        let len = columns.first().map_or(0, |c| c.len());
+        if len == 0 {
+            return Ok(Some(Float64Array::from((
+                "fused",
+                Box::new(arrow2::array::PrimitiveArray::<f64>::from_iter(
+                    std::iter::empty::<Option<f64>>(),
+                )),
+            ))));
+        }
+
+        let key = expr.shape_key();
+        let mut cache = kernel_cache().lock().unwrap();
+        let kernel = match cache.get(&key) {
+            Some(entry) => entry.clone(),
+            None => {
+                let compiled = compile(expr).ok().map(std::sync::Arc::new);
+                cache.insert(key, compiled.clone());
+                compiled
+            }
+        };
+        drop(cache);
+
+        let Some(kernel) = kernel else {
+            return Ok(None);
+        };
+
+        let buffers: Vec<*const f64> = columns
+            .iter()
+            .map(|c| c.as_arrow().values().as_ptr())
+            .collect();
+        let mut out = vec![0f64; len];
+        unsafe {
+            (kernel.func)(buffers.as_ptr(), len, out.as_mut_ptr());
+        }
+
+        let validity = combined_validity(columns);
+        let values = out.into_iter().enumerate().map(|(row, v)| {
+            let valid = validity
+                .as_ref()
+                .map_or(true, |validity| validity.get_bit(row));
+            valid.then_some(v)
+        });
+
+        Ok(Some(Float64Array::from((
+            "fused",
+            Box::new(arrow2::array::PrimitiveArray::<f64>::from_iter(values)),
+        ))))
+    }
+
+    /// Lowers `expr` into a Cranelift function looping over `len` rows,
+    /// computing the fused body for each, and writing it to `out`.
+    fn compile(expr: &FloatKernelExpr) -> DaftResult<CompiledKernel> {
+        let mut jit_builder = JITBuilder::new(cranelift_module::default_libcall_names())
+            .map_err(|e| DaftError::ComputeError(format!("Failed to create JIT builder: {e}")))?;
+        jit_builder.symbol_lookup_fn(Box::new(|_| None));
+        let mut module = JITModule::new(jit_builder);
+
+        let pointer_ty = module.target_config().pointer_type();
+        let mut sig = module.make_signature();
+        sig.params.push(AbiParam::new(pointer_ty)); // ptrs: *const *const f64
+        sig.params.push(AbiParam::new(pointer_ty)); // len: usize
+        sig.params.push(AbiParam::new(pointer_ty)); // out: *mut f64
+
+        let func_id = module
+            .declare_function("fused_kernel", Linkage::Export, &sig)
+            .map_err(|e| DaftError::ComputeError(format!("Failed to declare kernel: {e}")))?;
+
+        let mut ctx = module.make_context();
+        ctx.func.signature = sig;
+        let mut fn_builder_ctx = FunctionBuilderContext::new();
+        {
+            let mut builder = FunctionBuilder::new(&mut ctx.func, &mut fn_builder_ctx);
+            let entry = builder.create_block();
+            builder.append_block_params_for_function_params(entry);
+            builder.switch_to_block(entry);
+            builder.seal_block(entry);
+
+            let ptrs = builder.block_params(entry)[0];
+            let len = builder.block_params(entry)[1];
+            let out = builder.block_params(entry)[2];
+
+            let loop_block = builder.create_block();
+            let loop_block_body = builder.create_block();
+            let exit_block = builder.create_block();
+            let i = Variable::new(0);
+            builder.declare_var(i, pointer_ty);
+            let zero = builder.ins().iconst(pointer_ty, 0);
+            builder.def_var(i, zero);
+            builder.ins().jump(loop_block, &[]);
+
+            builder.switch_to_block(loop_block);
+            let i_val = builder.use_var(i);
+            let done = builder
+                .ins()
+                .icmp(IntCC::UnsignedGreaterThanOrEqual, i_val, len);
+            builder
+                .ins()
+                .brif(done, exit_block, &[], loop_block_body, &[]);
+
+            builder.switch_to_block(loop_block_body);
+
+            let value = lower(&mut builder, expr, ptrs, i_val, pointer_ty)?;
+            let i_wide = builder.ins().uextend(pointer_ty, i_val);
+            let byte_offset = builder
+                .ins()
+                .imul_imm(i_wide, std::mem::size_of::<f64>() as i64);
+            let out_ptr = builder.ins().iadd(out, byte_offset);
+            builder.ins().store(MemFlags::trusted(), value, out_ptr, 0);
+
+            let next_i = builder.ins().iadd_imm(i_val, 1);
+            builder.def_var(i, next_i);
+            builder.ins().jump(loop_block, &[]);
+
+            builder.switch_to_block(exit_block);
+            builder.seal_block(loop_block);
+            builder.seal_block(loop_block_body);
+            builder.seal_block(exit_block);
+            builder.ins().return_(&[]);
+            builder.finalize();
+        }
+
+        module
+            .define_function(func_id, &mut ctx)
+            .map_err(|e| DaftError::ComputeError(format!("Failed to define kernel: {e}")))?;
+        module.clear_context(&mut ctx);
+        module
+            .finalize_definitions()
+            .map_err(|e| DaftError::ComputeError(format!("Failed to finalize kernel: {e}")))?;
+
+        let code_ptr = module.get_finalized_function(func_id);
+        // Safety: `code_ptr` was just compiled from the signature declared
+        // above, which matches `KernelFn` exactly.
+        let func: KernelFn = unsafe { std::mem::transmute::<_, KernelFn>(code_ptr) };
+
+        Ok(CompiledKernel {
+            _module: module,
+            func,
+        })
+    }
+
+    /// Emits the Cranelift IR for `expr` at row index `i_val` (a `ptrs`-typed
+    /// integer), reading each referenced column through `ptrs[column]`.
+    fn lower(
+        builder: &mut FunctionBuilder,
+        expr: &FloatKernelExpr,
+        ptrs: cranelift_codegen::ir::Value,
+        i_val: cranelift_codegen::ir::Value,
+        pointer_ty: types::Type,
+    ) -> DaftResult<cranelift_codegen::ir::Value> {
+        Ok(match expr {
+            FloatKernelExpr::Column(idx) => {
+                let offset = (*idx as i64) * pointer_ty.bytes() as i64;
+                let col_ptr = builder
+                    .ins()
+                    .load(pointer_ty, MemFlags::trusted(), ptrs, offset as i32);
+                let i_wide = builder.ins().uextend(pointer_ty, i_val);
+                let elem_offset = builder
+                    .ins()
+                    .imul_imm(i_wide, std::mem::size_of::<f64>() as i64);
+                let addr = builder.ins().iadd(col_ptr, elem_offset);
+                builder.ins().load(types::F64, MemFlags::trusted(), addr, 0)
+            }
+            FloatKernelExpr::Literal(v) => builder.ins().f64const(*v),
+            FloatKernelExpr::Add(l, r) => {
+                let l = lower(builder, l, ptrs, i_val, pointer_ty)?;
+                let r = lower(builder, r, ptrs, i_val, pointer_ty)?;
+                builder.ins().fadd(l, r)
+            }
+            FloatKernelExpr::Sub(l, r) => {
+                let l = lower(builder, l, ptrs, i_val, pointer_ty)?;
+                let r = lower(builder, r, ptrs, i_val, pointer_ty)?;
+                builder.ins().fsub(l, r)
+            }
+            FloatKernelExpr::Mul(l, r) => {
+                let l = lower(builder, l, ptrs, i_val, pointer_ty)?;
+                let r = lower(builder, r, ptrs, i_val, pointer_ty)?;
+                builder.ins().fmul(l, r)
+            }
+            FloatKernelExpr::Div(l, r) => {
+                let l = lower(builder, l, ptrs, i_val, pointer_ty)?;
+                let r = lower(builder, r, ptrs, i_val, pointer_ty)?;
+                builder.ins().fdiv(l, r)
+            }
+            FloatKernelExpr::Sqrt(v) => {
+                let v = lower(builder, v, ptrs, i_val, pointer_ty)?;
+                builder.ins().sqrt(v)
+            }
+            FloatKernelExpr::Abs(v) => {
+                let v = lower(builder, v, ptrs, i_val, pointer_ty)?;
+                builder.ins().fabs(v)
+            }
+            // `ln` has no Cranelift intrinsic; the caller falls back to the
+            // interpreter for any IR containing it.
+            FloatKernelExpr::Ln(_) => {
+                return Err(DaftError::ComputeError(
+                    "jit kernel does not support ln, falling back to interpreter".to_string(),
+                ))
+            }
+        })
+    }
+
+    use cranelift_codegen::ir::{condcodes::IntCC, MemFlags};
+    use cranelift_entity::EntityRef;
+    use cranelift_frontend::Variable;
+}
@@ -1,14 +1,29 @@
 use common_error::DaftResult;
-use num_traits::Float;
 
-use crate::{array::DataArray, datatypes::DaftNumericType};
+use crate::{
+    array::{
+        ops::jit::{eval_fused, FloatKernelExpr},
+        DataArray,
+    },
+    datatypes::{Float32Type, Float64Type},
+};
 
-impl<T> DataArray<T>
-where
-    T: DaftNumericType,
-    T::Native: Float,
-{
+impl DataArray<Float32Type> {
     pub fn sqrt(&self) -> DaftResult<Self> {
         self.apply(|v| v.sqrt())
     }
 }
+
+impl DataArray<Float64Type> {
+    /// Goes through [`eval_fused`] (a single-node `Sqrt(Column(0))` IR)
+    /// instead of a plain per-element `apply`, so a standalone `sqrt` shares
+    /// the same evaluation path -- and JIT-compiled kernel cache, when the
+    /// `jit` feature is enabled -- that a fused chain like `sqrt(a*a+b*b)`
+    /// would use, rather than duplicating its own per-element loop.
+    pub fn sqrt(&self) -> DaftResult<Self> {
+        eval_fused(
+            &FloatKernelExpr::Sqrt(Box::new(FloatKernelExpr::Column(0))),
+            &[self],
+        )
+    }
+}
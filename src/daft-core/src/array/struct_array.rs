@@ -1,13 +1,25 @@
 use std::sync::Arc;
 
 use common_error::{DaftError, DaftResult};
+use sketches_ddsketch::DDSketch;
 
 use crate::{
-    array::growable::{Growable, GrowableArray},
-    datatypes::{DaftArrayType, DataType, Field},
-    series::Series,
+    array::{
+        growable::{Growable, GrowableArray},
+        ops::as_arrow::AsArrow,
+    },
+    datatypes::{
+        BinaryArray, BooleanArray, DaftArrayType, DataType, Field, Float64Array, Int64Array,
+        ListArray, NullArray, Utf8Array,
+    },
+    series::{IntoSeries, Series},
 };
 
+/// Name of the binary child column that a sketch `StructArray` uses to store
+/// each row's serialized [`DDSketch`], matching the layout produced by the
+/// `MergeSketch`/`Sketch` aggregations.
+const SKETCH_COLUMN_NAME: &str = "sketch";
+
 #[derive(Clone, Debug)]
 pub struct StructArray {
     pub field: Arc<Field>,
@@ -179,4 +191,347 @@ impl StructArray {
             validity,
         ))
     }
+
+    /// Merges every row's sketch into a single sketch, collapsing this array
+    /// down to a single row. This is the combine half of a distributed
+    /// sketch-based aggregation (e.g. merging per-partition `sketch_percentile`
+    /// sketches before computing the final quantiles).
+    pub fn sketch_merge(&self) -> DaftResult<Self> {
+        let sketch_col = self
+            .children
+            .iter()
+            .find(|s| s.name() == SKETCH_COLUMN_NAME)
+            .ok_or_else(|| {
+                DaftError::ValueError(format!(
+                    "sketch_merge expected a \"{SKETCH_COLUMN_NAME}\" binary child column, found: {:?}",
+                    self.children.iter().map(Series::name).collect::<Vec<_>>()
+                ))
+            })?;
+        let sketch_col = sketch_col.binary()?;
+
+        let mut merged: Option<DDSketch> = None;
+        // `.iter()` (unlike `.values_iter()`) honors the column's validity
+        // bitmap, so a null sketch row -- e.g. an empty partition in a
+        // partial-aggregation group -- is skipped instead of being handed to
+        // `bincode::deserialize` as empty bytes, which would otherwise error.
+        for bytes in sketch_col.as_arrow().iter().flatten() {
+            let sketch: DDSketch = bincode::deserialize(bytes).map_err(|e| {
+                DaftError::ValueError(format!("Failed to deserialize sketch: {e}"))
+            })?;
+            merged = Some(match merged {
+                Some(mut acc) => {
+                    acc.merge(&sketch).map_err(|e| {
+                        DaftError::ValueError(format!("Failed to merge sketches: {e}"))
+                    })?;
+                    acc
+                }
+                None => sketch,
+            });
+        }
+
+        let merged_bytes = merged
+            .as_ref()
+            .map(bincode::serialize)
+            .transpose()
+            .map_err(|e| DaftError::ValueError(format!("Failed to serialize merged sketch: {e}")))?;
+        let merged_sketch_array = BinaryArray::from((
+            SKETCH_COLUMN_NAME,
+            arrow2::array::BinaryArray::<i64>::from_iter([merged_bytes]),
+        ));
+
+        Self::new(
+            self.field.clone(),
+            vec![merged_sketch_array.into_series()],
+            None,
+        )
+    }
+
+    /// Builds a [`StructArray`] from row-oriented data — each row a set of
+    /// field name/value pairs — inferring the struct schema (including
+    /// nested `Struct`/`List` children) as rows come in, rather than
+    /// requiring the caller to pre-build per-field [`Series`] with an
+    /// exactly matching dtype. See [`StructArrayBuilder`] for the
+    /// widening/padding rules this applies.
+    pub fn from_rows(
+        name: &str,
+        rows: impl IntoIterator<Item = Vec<(String, RowValue)>>,
+    ) -> DaftResult<Self> {
+        let mut builder = StructArrayBuilder::new();
+        for row in rows {
+            builder.push_row(row)?;
+        }
+        builder.build(name)
+    }
+}
+
+/// A single row-oriented scalar value accepted by [`StructArray::from_rows`].
+/// `List`/`Struct` recurse into this same type for their children.
+#[derive(Clone, Debug)]
+pub enum RowValue {
+    Null,
+    Boolean(bool),
+    Int64(i64),
+    Float64(f64),
+    Utf8(String),
+    Binary(Vec<u8>),
+    List(Vec<RowValue>),
+    Struct(Vec<(String, RowValue)>),
+}
+
+impl RowValue {
+    fn dtype(&self) -> DataType {
+        match self {
+            Self::Null => DataType::Null,
+            Self::Boolean(_) => DataType::Boolean,
+            Self::Int64(_) => DataType::Int64,
+            Self::Float64(_) => DataType::Float64,
+            Self::Utf8(_) => DataType::Utf8,
+            Self::Binary(_) => DataType::Binary,
+            Self::List(values) => {
+                let inner = values.iter().fold(DataType::Null, |acc, v| {
+                    widen_dtype(&acc, &v.dtype()).unwrap_or(acc)
+                });
+                DataType::List(Box::new(inner))
+            }
+            Self::Struct(fields) => DataType::Struct(
+                fields
+                    .iter()
+                    .map(|(name, value)| Field::new(name, value.dtype()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Returns the least-common supertype of `a` and `b`, widening rather than
+/// erroring on the common cases (e.g. `Int64` + `Float64` → `Float64`); any
+/// dtype paired with `Null` stays as the non-null dtype, since seeing `Null`
+/// just means "no value seen yet there", not "must be null-typed".
+fn widen_dtype(a: &DataType, b: &DataType) -> DaftResult<DataType> {
+    Ok(match (a, b) {
+        (a, b) if a == b => a.clone(),
+        (DataType::Null, other) | (other, DataType::Null) => other.clone(),
+        (DataType::Int64, DataType::Float64) | (DataType::Float64, DataType::Int64) => {
+            DataType::Float64
+        }
+        (DataType::List(a_inner), DataType::List(b_inner)) => {
+            DataType::List(Box::new(widen_dtype(a_inner, b_inner)?))
+        }
+        (DataType::Struct(a_fields), DataType::Struct(b_fields)) => {
+            DataType::Struct(merge_struct_fields(a_fields, b_fields)?)
+        }
+        (a, b) => {
+            return Err(DaftError::TypeError(format!(
+                "No common supertype for {a} and {b} while inferring a struct schema from rows"
+            )));
+        }
+    })
+}
+
+/// Merges two `Struct` field lists the same way row-wise field accumulation
+/// does: a field present on only one side is carried over as-is (rows
+/// missing it are implicitly nullable there), a field present on both sides
+/// is widened, and insertion order follows first sight across both sides.
+fn merge_struct_fields(a: &[Field], b: &[Field]) -> DaftResult<Vec<Field>> {
+    let mut merged: Vec<Field> = a.to_vec();
+    for field in b {
+        if let Some(existing) = merged.iter_mut().find(|f| f.name == field.name) {
+            existing.dtype = widen_dtype(&existing.dtype, &field.dtype)?;
+        } else {
+            merged.push(field.clone());
+        }
+    }
+    Ok(merged)
+}
+
+/// Accumulates rows (each a set of field name/value pairs) into a
+/// [`StructArray`], inferring the struct schema incrementally.
+///
+/// Fields are tracked in first-seen order; a row missing a previously-seen
+/// field is padded with null for it, and a newly-seen field is backfilled
+/// with leading nulls for the rows that preceded it. A column that never
+/// sees a non-null value defaults to a nullable `Null`-typed field.
+#[derive(Default)]
+pub struct StructArrayBuilder {
+    fields: Vec<(String, DataType, Vec<Option<RowValue>>)>,
+    len: usize,
+}
+
+impl StructArrayBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accumulates one row. Fields absent from `row` are padded with null;
+    /// fields not seen in any prior row are backfilled with leading nulls.
+    pub fn push_row(&mut self, row: Vec<(String, RowValue)>) -> DaftResult<()> {
+        for (name, value) in row {
+            let value_dtype = value.dtype();
+            if let Some((_, dtype, values)) = self.fields.iter_mut().find(|(n, _, _)| *n == name)
+            {
+                *dtype = widen_dtype(dtype, &value_dtype)?;
+                values.push(Some(value));
+            } else {
+                let mut values = vec![None; self.len];
+                values.push(Some(value));
+                self.fields.push((name, value_dtype, values));
+            }
+        }
+
+        for (_, _, values) in &mut self.fields {
+            if values.len() == self.len {
+                values.push(None);
+            }
+        }
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Finishes the build, producing a [`StructArray`] named `name` whose
+    /// `field` reflects the inferred `DataType::Struct`.
+    pub fn build(self, name: &str) -> DaftResult<StructArray> {
+        let mut children = Vec::with_capacity(self.fields.len());
+        for (field_name, dtype, values) in self.fields {
+            children.push(rowvalues_to_series(&field_name, &dtype, values)?);
+        }
+
+        let field = Field::new(
+            name,
+            DataType::Struct(
+                children
+                    .iter()
+                    .map(|s| Field::new(s.name(), s.data_type().clone()))
+                    .collect(),
+            ),
+        );
+
+        Ok(StructArray::new(field, children, None))
+    }
+}
+
+fn rowvalues_to_series(name: &str, dtype: &DataType, values: Vec<Option<RowValue>>) -> DaftResult<Series> {
+    match dtype {
+        DataType::Null => {
+            let arr = arrow2::array::NullArray::new(arrow2::datatypes::DataType::Null, values.len());
+            Ok(NullArray::from((name, Box::new(arr) as Box<dyn arrow2::array::Array>)).into_series())
+        }
+        DataType::Boolean => {
+            let arr = arrow2::array::BooleanArray::from_iter(values.into_iter().map(|v| match v {
+                Some(RowValue::Boolean(b)) => Some(b),
+                Some(RowValue::Null) | None => None,
+                Some(_) => unreachable!("boolean column received a non-boolean value"),
+            }));
+            Ok(BooleanArray::from((name, Box::new(arr))).into_series())
+        }
+        DataType::Int64 => {
+            let arr = arrow2::array::PrimitiveArray::<i64>::from_iter(values.into_iter().map(
+                |v| match v {
+                    Some(RowValue::Int64(i)) => Some(i),
+                    Some(RowValue::Null) | None => None,
+                    Some(_) => unreachable!("int64 column received a non-integer value"),
+                },
+            ));
+            Ok(Int64Array::from((name, Box::new(arr))).into_series())
+        }
+        DataType::Float64 => {
+            let arr = arrow2::array::PrimitiveArray::<f64>::from_iter(values.into_iter().map(
+                |v| match v {
+                    Some(RowValue::Int64(i)) => Some(i as f64),
+                    Some(RowValue::Float64(f)) => Some(f),
+                    Some(RowValue::Null) | None => None,
+                    Some(_) => unreachable!("float64 column received a non-numeric value"),
+                },
+            ));
+            Ok(Float64Array::from((name, Box::new(arr))).into_series())
+        }
+        DataType::Utf8 => {
+            let arr = arrow2::array::Utf8Array::<i64>::from_iter(values.into_iter().map(|v| {
+                match v {
+                    Some(RowValue::Utf8(s)) => Some(s),
+                    Some(RowValue::Null) | None => None,
+                    Some(_) => unreachable!("utf8 column received a non-string value"),
+                }
+            }));
+            Ok(Utf8Array::from((name, Box::new(arr))).into_series())
+        }
+        DataType::Binary => {
+            let arr = arrow2::array::BinaryArray::<i64>::from_iter(values.into_iter().map(|v| {
+                match v {
+                    Some(RowValue::Binary(b)) => Some(b),
+                    Some(RowValue::Null) | None => None,
+                    Some(_) => unreachable!("binary column received a non-binary value"),
+                }
+            }));
+            Ok(BinaryArray::from((name, Box::new(arr))).into_series())
+        }
+        DataType::List(inner) => build_list_series(name, inner, values),
+        DataType::Struct(fields) => build_struct_series(name, fields, values),
+        other => Err(DaftError::TypeError(format!(
+            "StructArray::from_rows does not support inferring column dtype {other}"
+        ))),
+    }
+}
+
+fn build_list_series(name: &str, inner: &DataType, values: Vec<Option<RowValue>>) -> DaftResult<Series> {
+    let mut offsets: Vec<i64> = Vec::with_capacity(values.len() + 1);
+    offsets.push(0);
+    let mut flat_values: Vec<Option<RowValue>> = Vec::new();
+    let mut validity_bits = Vec::with_capacity(values.len());
+
+    for value in values {
+        match value {
+            Some(RowValue::List(elems)) => {
+                flat_values.extend(elems.into_iter().map(Some));
+                validity_bits.push(true);
+            }
+            Some(RowValue::Null) | None => validity_bits.push(false),
+            Some(_) => unreachable!("list column received a non-list value"),
+        }
+        offsets.push(flat_values.len() as i64);
+    }
+
+    let child_series = rowvalues_to_series(name, inner, flat_values)?;
+    let validity = if validity_bits.iter().all(|b| *b) {
+        None
+    } else {
+        Some(arrow2::bitmap::Bitmap::from(validity_bits))
+    };
+    let offsets_buffer = arrow2::offset::OffsetsBuffer::try_from(offsets)
+        .map_err(|e| DaftError::ValueError(format!("Invalid list offsets while building a row-oriented list column: {e}")))?;
+
+    Ok(ListArray::new(
+        Field::new(name, DataType::List(Box::new(inner.clone()))),
+        child_series,
+        offsets_buffer,
+        validity,
+    )
+    .into_series())
+}
+
+fn build_struct_series(name: &str, _fields: &[Field], values: Vec<Option<RowValue>>) -> DaftResult<Series> {
+    let mut builder = StructArrayBuilder::new();
+    let mut validity_bits = Vec::with_capacity(values.len());
+
+    for value in values {
+        match value {
+            Some(RowValue::Struct(row)) => {
+                builder.push_row(row)?;
+                validity_bits.push(true);
+            }
+            Some(RowValue::Null) | None => {
+                builder.push_row(Vec::new())?;
+                validity_bits.push(false);
+            }
+            Some(_) => unreachable!("struct column received a non-struct value"),
+        }
+    }
+
+    let validity = if validity_bits.iter().all(|b| *b) {
+        None
+    } else {
+        Some(arrow2::bitmap::Bitmap::from(validity_bits))
+    };
+
+    Ok(builder.build(name)?.with_validity(validity)?.into_series())
 }
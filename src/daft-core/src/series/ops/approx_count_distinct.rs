@@ -0,0 +1,194 @@
+use common_error::{DaftError, DaftResult};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    array::ops::as_arrow::AsArrow,
+    datatypes::{BinaryArray, UInt64Array},
+    series::{IntoSeries, Series},
+};
+
+/// Default number of HyperLogLog register-index bits, giving a standard
+/// error of ~1.04/sqrt(2^14) ≈ 0.8%.
+const DEFAULT_PRECISION: u8 = 14;
+
+/// A HyperLogLog sketch for approximating the number of distinct values
+/// hashed into it, with accuracy tunable via `precision` (the number of bits
+/// used to select a register out of `2^precision`).
+///
+/// Derives `Serialize`/`Deserialize` so it has its own mergeable serialized
+/// form, the same way [`sketches_ddsketch::DDSketch`] does for quantile
+/// sketches: a partial `HyperLogLog` built over one partition's rows can be
+/// shipped as bytes and combined with another partition's via [`Self::merge`]
+/// before a final [`Self::estimate`] is taken, instead of requiring every row
+/// to be visible to a single `estimate` call.
+#[derive(Serialize, Deserialize)]
+struct HyperLogLog {
+    registers: Vec<u8>,
+    precision: u8,
+}
+
+impl HyperLogLog {
+    fn new(precision: u8) -> Self {
+        Self {
+            registers: vec![0u8; 1 << precision],
+            precision,
+        }
+    }
+
+    fn add_hash(&mut self, hash: u64) {
+        let num_registers = self.registers.len() as u64;
+        let index = (hash & (num_registers - 1)) as usize;
+        let rest = hash >> self.precision;
+        // +1 because we want the position of the lowest set bit, 1-indexed.
+        let rank = (rest.trailing_zeros() as u8) + 1;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+
+    /// Merges `other` into `self` by taking the register-wise max, the
+    /// standard way to combine two HyperLogLog sketches built with the same
+    /// precision.
+    fn merge(&mut self, other: &Self) -> DaftResult<()> {
+        if self.precision != other.precision {
+            return Err(DaftError::ValueError(format!(
+                "Cannot merge HyperLogLog sketches built with different precision: {} vs {}",
+                self.precision, other.precision
+            )));
+        }
+        for (r, other_r) in self.registers.iter_mut().zip(other.registers.iter()) {
+            *r = (*r).max(*other_r);
+        }
+        Ok(())
+    }
+
+    fn estimate(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|&r| 2f64.powi(-(r as i32)))
+            .sum();
+        let raw_estimate = alpha * m * m / sum;
+
+        // Small-range correction: fall back to linear counting when many
+        // registers are still empty, as in the original HLL paper.
+        let zeros = self.registers.iter().filter(|&&r| r == 0).count();
+        let estimate = if raw_estimate <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            raw_estimate
+        };
+
+        estimate.round() as u64
+    }
+}
+
+impl Series {
+    fn build_hll(&self, precision: Option<u8>) -> DaftResult<HyperLogLog> {
+        let precision = precision.unwrap_or(DEFAULT_PRECISION);
+        let hashes = self.hash(None)?;
+
+        let mut hll = HyperLogLog::new(precision);
+        let validity = self.validity();
+        for (i, hash) in hashes.as_arrow().values_iter().enumerate() {
+            if validity.map_or(true, |v| v.get_bit(i)) {
+                hll.add_hash(*hash);
+            }
+        }
+        Ok(hll)
+    }
+
+    /// Approximates the number of distinct non-null values in this series
+    /// using a HyperLogLog sketch. `precision` controls the accuracy/memory
+    /// tradeoff (more register bits → lower error, more memory); defaults to
+    /// [`DEFAULT_PRECISION`] when `None`.
+    pub fn approx_count_distinct(&self, precision: Option<u8>) -> DaftResult<Self> {
+        let estimate = self.build_hll(precision)?.estimate();
+        let array = Box::new(arrow2::array::PrimitiveArray::from_iter([Some(estimate)]));
+        let result = UInt64Array::from((self.name(), array));
+        Ok(result.into_series())
+    }
+
+    /// Builds a HyperLogLog sketch over this series' hashed values the same
+    /// way [`Self::approx_count_distinct`] does, but returns it in its
+    /// serialized form instead of immediately reading off an estimate --
+    /// this is the mergeable sketch a distributed partial aggregation needs:
+    /// one partition's serialized bytes can be combined with another's via
+    /// [`Self::sketch_distinct_merge`], with [`Self::approx_count_distinct_finalize`]
+    /// only run once, on the fully-merged sketch.
+    pub fn sketch_distinct(&self, precision: Option<u8>) -> DaftResult<Self> {
+        let hll = self.build_hll(precision)?;
+        let bytes = bincode::serialize(&hll)
+            .map_err(|e| DaftError::ValueError(format!("Failed to serialize sketch: {e}")))?;
+        let array = BinaryArray::from((
+            self.name(),
+            arrow2::array::BinaryArray::<i64>::from_iter([Some(bytes)]),
+        ));
+        Ok(array.into_series())
+    }
+
+    /// Merges every row of a serialized-HyperLogLog `Binary` column (as
+    /// produced by [`Self::sketch_distinct`]) into a single row, combining
+    /// one partition's sketch with another's via [`HyperLogLog::merge`]'s
+    /// register-wise max. A null row (e.g. an empty partition) is skipped.
+    pub fn sketch_distinct_merge(&self) -> DaftResult<Self> {
+        let sketch_col = self.binary()?;
+
+        let mut merged: Option<HyperLogLog> = None;
+        for bytes in sketch_col.as_arrow().iter().flatten() {
+            let sketch: HyperLogLog = bincode::deserialize(bytes).map_err(|e| {
+                DaftError::ValueError(format!("Failed to deserialize sketch: {e}"))
+            })?;
+            merged = Some(match merged {
+                Some(mut acc) => {
+                    acc.merge(&sketch)?;
+                    acc
+                }
+                None => sketch,
+            });
+        }
+
+        let merged_bytes = merged
+            .as_ref()
+            .map(bincode::serialize)
+            .transpose()
+            .map_err(|e| DaftError::ValueError(format!("Failed to serialize merged sketch: {e}")))?;
+        let array = BinaryArray::from((
+            self.name(),
+            arrow2::array::BinaryArray::<i64>::from_iter([merged_bytes]),
+        ));
+        Ok(array.into_series())
+    }
+
+    /// Reads the final distinct-count estimate out of a (possibly merged)
+    /// serialized HyperLogLog sketch, as produced by [`Self::sketch_distinct`]
+    /// or [`Self::sketch_distinct_merge`].
+    pub fn approx_count_distinct_finalize(&self) -> DaftResult<Self> {
+        let sketch_col = self.binary()?;
+        let estimates = sketch_col
+            .as_arrow()
+            .iter()
+            .map(|bytes| {
+                bytes
+                    .map(|bytes| {
+                        bincode::deserialize::<HyperLogLog>(bytes)
+                            .map(|hll| hll.estimate())
+                            .map_err(|e| {
+                                DaftError::ValueError(format!("Failed to deserialize sketch: {e}"))
+                            })
+                    })
+                    .transpose()
+            })
+            .collect::<DaftResult<Vec<Option<u64>>>>()?;
+
+        let array = Box::new(arrow2::array::PrimitiveArray::from_iter(estimates));
+        let result = UInt64Array::from((self.name(), array));
+        Ok(result.into_series())
+    }
+}
@@ -0,0 +1,56 @@
+use common_error::{DaftError, DaftResult};
+
+use super::cast_series_to_supertype;
+use crate::series::Series;
+
+impl Series {
+    /// Vectorized CASE/WHEN: evaluates `conditions` in priority order against
+    /// `values`, taking the first branch whose predicate is true per row, and
+    /// falling back to `default` where none match.
+    pub fn case_when(
+        conditions: &[&Self],
+        values: &[&Self],
+        default: &Self,
+    ) -> DaftResult<Self> {
+        if conditions.len() != values.len() {
+            return Err(DaftError::ValueError(format!(
+                "case_when expects the same number of conditions and values, got {} conditions and {} values",
+                conditions.len(),
+                values.len()
+            )));
+        }
+
+        let mut all_values = values.to_vec();
+        all_values.push(default);
+        let casted_values = cast_series_to_supertype(&all_values)?;
+        assert_eq!(casted_values.len(), all_values.len());
+
+        let (branches, default) = casted_values.split_at(casted_values.len() - 1);
+        let default = &default[0];
+
+        let mut result = default.clone();
+        for (condition, value) in conditions.iter().zip(branches.iter()).rev() {
+            result = value.if_else(&result, condition)?;
+        }
+        Ok(result)
+    }
+
+    /// Returns the first non-null value per row across `inputs`, casting all
+    /// inputs to a common supertype via [`cast_series_to_supertype`].
+    pub fn coalesce(inputs: &[&Self]) -> DaftResult<Self> {
+        if inputs.is_empty() {
+            return Err(DaftError::ValueError(
+                "coalesce expects at least one input".to_string(),
+            ));
+        }
+
+        let casted_inputs = cast_series_to_supertype(inputs)?;
+        let mut iter = casted_inputs.into_iter().rev();
+        let mut result = iter.next().unwrap();
+        for input in iter {
+            let is_valid = input.not_null()?;
+            result = input.if_else(&result, &is_valid)?;
+        }
+        Ok(result)
+    }
+}
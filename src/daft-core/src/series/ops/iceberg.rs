@@ -0,0 +1,96 @@
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    datatypes::{DataType, TimeUnit},
+    series::{IntoSeries, Series},
+};
+
+impl Series {
+    /// Iceberg `bucket[N]` partition transform: dispatches to the concrete
+    /// array's `iceberg_bucket`, which handles the type-specific
+    /// serialization rules before hashing.
+    pub fn iceberg_bucket(&self, n: i32) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Int8 => Ok(self.i8()?.iceberg_bucket(n)?.into_series()),
+            DataType::Int16 => Ok(self.i16()?.iceberg_bucket(n)?.into_series()),
+            DataType::Int32 => Ok(self.i32()?.iceberg_bucket(n)?.into_series()),
+            DataType::Int64 => Ok(self.i64()?.iceberg_bucket(n)?.into_series()),
+            DataType::UInt8 => Ok(self.u8()?.iceberg_bucket(n)?.into_series()),
+            DataType::UInt16 => Ok(self.u16()?.iceberg_bucket(n)?.into_series()),
+            DataType::UInt32 => Ok(self.u32()?.iceberg_bucket(n)?.into_series()),
+            DataType::UInt64 => Ok(self.u64()?.iceberg_bucket(n)?.into_series()),
+            DataType::Decimal128(..) => Ok(self.decimal128()?.iceberg_bucket(n)?.into_series()),
+            DataType::Utf8 => Ok(self.utf8()?.iceberg_bucket(n)?.into_series()),
+            DataType::Binary => Ok(self.binary()?.iceberg_bucket(n)?.into_series()),
+            other => Err(DaftError::TypeError(format!(
+                "iceberg_bucket is not implemented for type {other}"
+            ))),
+        }
+    }
+
+    /// Iceberg `year` partition transform: years since 1970, for `Date` and
+    /// `Timestamp` columns. `Timestamp` columns are first cast down to
+    /// `Date` so only one code path derives the calendar year.
+    pub fn iceberg_year(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => Ok(self.date()?.iceberg_year()?.into_series()),
+            DataType::Timestamp(..) => Ok(self
+                .cast(&DataType::Date)?
+                .date()?
+                .iceberg_year()?
+                .into_series()),
+            other => Err(DaftError::TypeError(format!(
+                "iceberg_year is not implemented for type {other}"
+            ))),
+        }
+    }
+
+    /// Iceberg `month` partition transform: `year * 12 + month` months since
+    /// 1970, for `Date` and `Timestamp` columns.
+    pub fn iceberg_month(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => Ok(self.date()?.iceberg_month()?.into_series()),
+            DataType::Timestamp(..) => Ok(self
+                .cast(&DataType::Date)?
+                .date()?
+                .iceberg_month()?
+                .into_series()),
+            other => Err(DaftError::TypeError(format!(
+                "iceberg_month is not implemented for type {other}"
+            ))),
+        }
+    }
+
+    /// Iceberg `day` partition transform: days since 1970-01-01, for `Date`
+    /// and `Timestamp` columns.
+    pub fn iceberg_day(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Date => Ok(self.date()?.iceberg_day()?.into_series()),
+            DataType::Timestamp(..) => Ok(self
+                .cast(&DataType::Date)?
+                .date()?
+                .iceberg_day()?
+                .into_series()),
+            other => Err(DaftError::TypeError(format!(
+                "iceberg_day is not implemented for type {other}"
+            ))),
+        }
+    }
+
+    /// Iceberg `hour` partition transform: hours since epoch, for
+    /// `Timestamp` columns. The column is first cast to microsecond
+    /// resolution so the underlying array's hard-coded micros-per-hour
+    /// arithmetic is always operating on the unit it expects.
+    pub fn iceberg_hour(&self) -> DaftResult<Self> {
+        match self.data_type() {
+            DataType::Timestamp(..) => Ok(self
+                .cast(&DataType::Timestamp(TimeUnit::Microseconds, None))?
+                .timestamp()?
+                .iceberg_hour()?
+                .into_series()),
+            other => Err(DaftError::TypeError(format!(
+                "iceberg_hour is not implemented for type {other}"
+            ))),
+        }
+    }
+}
@@ -20,4 +20,18 @@ impl Series {
             ))),
         }
     }
+
+    /// Merges every row of a sketch `Struct` column into a single row,
+    /// combining partial sketches (e.g. one per partition) into one.
+    pub fn sketch_merge(&self) -> DaftResult<Self> {
+        use crate::datatypes::DataType::*;
+
+        match self.data_type() {
+            Struct(_) => Ok(self.struct_()?.sketch_merge()?.into_series()),
+            other => Err(DaftError::TypeError(format!(
+                "sketch_merge is not implemented for type {}",
+                other
+            ))),
+        }
+    }
 }
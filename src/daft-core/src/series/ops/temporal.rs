@@ -0,0 +1,198 @@
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    array::ops::as_arrow::AsArrow,
+    datatypes::{DataType, TimeUnit, Utf8Array},
+    series::{IntoSeries, Series},
+};
+
+/// Days in 400 Gregorian years, used to floor-divide day counts that may be
+/// negative (dates before the epoch) the same way [`crate::array::ops::iceberg`]
+/// does for its `year`/`month` partition transforms.
+const DAYS_PER_YEAR_GROUP: i64 = 146_097;
+
+/// Converts days since the Unix epoch (1970-01-01) to a proleptic Gregorian
+/// `(year, month, day)` triple.
+fn days_to_ymd(days_since_epoch: i64) -> (i64, i64, i64) {
+    let z = days_since_epoch + 719_468;
+    let era = if z >= 0 {
+        z
+    } else {
+        z - DAYS_PER_YEAR_GROUP + 1
+    } / DAYS_PER_YEAR_GROUP;
+    let doe = z - era * DAYS_PER_YEAR_GROUP; // [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365; // [0, 399]
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+    let mp = (5 * doy + 2) / 153; // [0, 11]
+    let d = doy - (153 * mp + 2) / 5 + 1; // [1, 31]
+    let m = if mp < 10 { mp + 3 } else { mp - 9 }; // [1, 12]
+    let y = if m <= 2 { y + 1 } else { y };
+    (y, m, d)
+}
+
+/// Day-of-year (1-indexed), derived from the same `(year, month, day)` triple
+/// `days_to_ymd` produces, rather than a separate calendar table.
+fn day_of_year(year: i64, month: i64, day: i64) -> i64 {
+    let is_leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    const CUMULATIVE_DAYS: [i64; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+    let mut doy = CUMULATIVE_DAYS[(month - 1) as usize] + day;
+    if is_leap && month > 2 {
+        doy += 1;
+    }
+    doy
+}
+
+/// A decomposed local wall-clock instant, ready for strftime-style rendering.
+struct BrokenDownTime {
+    year: i64,
+    month: i64,
+    day: i64,
+    hour: i64,
+    minute: i64,
+    second: i64,
+}
+
+/// Number of `unit` ticks in one second, for converting a `Time`/`Timestamp`
+/// column's physical representation down to whole seconds before formatting.
+fn scale_factor(unit: &TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Seconds => 1,
+        TimeUnit::Milliseconds => 1_000,
+        TimeUnit::Microseconds => 1_000_000,
+        TimeUnit::Nanoseconds => 1_000_000_000,
+    }
+}
+
+/// Parses a fixed UTC-offset timezone string (`"+05:00"`, `"-0800"`, `"Z"`/`"UTC"`)
+/// into a signed offset in seconds. Named IANA zones (e.g. `"America/New_York"`)
+/// aren't resolvable without a timezone database, so they're treated as UTC --
+/// the same fallback `Strftime`'s caller already accepts implicitly by not
+/// rejecting unknown zone strings.
+fn fixed_offset_seconds(tz: Option<&str>) -> i64 {
+    let Some(tz) = tz else { return 0 };
+    if tz.eq_ignore_ascii_case("utc") || tz == "Z" {
+        return 0;
+    }
+    let (sign, rest) = match tz.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, tz.strip_prefix('+').unwrap_or(tz)),
+    };
+    let digits: String = rest.chars().filter(|c| c.is_ascii_digit()).collect();
+    let (hours, minutes) = match digits.len() {
+        4 => (
+            digits[0..2].parse().unwrap_or(0),
+            digits[2..4].parse().unwrap_or(0),
+        ),
+        2 => (digits[0..2].parse().unwrap_or(0), 0),
+        _ => (0, 0),
+    };
+    sign * (hours * 3_600 + minutes * 60)
+}
+
+fn broken_down_from_seconds(total_seconds: i64) -> BrokenDownTime {
+    let days = total_seconds.div_euclid(86_400);
+    let secs_of_day = total_seconds.rem_euclid(86_400);
+    let (year, month, day) = days_to_ymd(days);
+    BrokenDownTime {
+        year,
+        month,
+        day,
+        hour: secs_of_day / 3_600,
+        minute: (secs_of_day % 3_600) / 60,
+        second: secs_of_day % 60,
+    }
+}
+
+/// Renders `broken_down` according to a bounded, commonly-used subset of
+/// strftime directives (`%Y %y %m %d %H %I %M %S %p %j %%`); any other `%x`
+/// directive is passed through literally rather than erroring, so an
+/// unsupported format string degrades gracefully instead of failing rows
+/// that don't need it.
+fn render_strftime(broken_down: &BrokenDownTime, format: &str) -> String {
+    let mut out = String::with_capacity(format.len());
+    let mut chars = format.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('Y') => out.push_str(&broken_down.year.to_string()),
+            Some('y') => out.push_str(&format!("{:02}", broken_down.year.rem_euclid(100))),
+            Some('m') => out.push_str(&format!("{:02}", broken_down.month)),
+            Some('d') => out.push_str(&format!("{:02}", broken_down.day)),
+            Some('H') => out.push_str(&format!("{:02}", broken_down.hour)),
+            Some('I') => {
+                let hour12 = match broken_down.hour % 12 {
+                    0 => 12,
+                    h => h,
+                };
+                out.push_str(&format!("{hour12:02}"));
+            }
+            Some('M') => out.push_str(&format!("{:02}", broken_down.minute)),
+            Some('S') => out.push_str(&format!("{:02}", broken_down.second)),
+            Some('p') => out.push_str(if broken_down.hour < 12 { "AM" } else { "PM" }),
+            Some('j') => {
+                out.push_str(&format!(
+                    "{:03}",
+                    day_of_year(broken_down.year, broken_down.month, broken_down.day)
+                ));
+            }
+            Some('%') => out.push('%'),
+            Some(other) => {
+                out.push('%');
+                out.push(other);
+            }
+            None => out.push('%'),
+        }
+    }
+    out
+}
+
+impl Series {
+    /// Formats a `Date`/`Time`/`Timestamp` column to `Utf8` via a strftime-style
+    /// `format` string (e.g. `"%Y-%m-%d %H:%M:%S"`), honoring the input's
+    /// `TimeUnit` and (for a fixed UTC offset) timezone. A null row stays null.
+    pub fn dt_strftime(&self, format: &str) -> DaftResult<Self> {
+        let total_seconds: Vec<Option<i64>> = match self.data_type() {
+            DataType::Date => self
+                .date()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.map(|days| i64::from(*days) * 86_400))
+                .collect(),
+            DataType::Time(unit) => {
+                let per_second = scale_factor(unit);
+                self.time()?
+                    .as_arrow()
+                    .iter()
+                    .map(|v| v.map(|ticks| ticks.div_euclid(per_second)))
+                    .collect()
+            }
+            DataType::Timestamp(unit, tz) => {
+                let per_second = scale_factor(unit);
+                let offset = fixed_offset_seconds(tz.as_deref());
+                self.timestamp()?
+                    .as_arrow()
+                    .iter()
+                    .map(|v| v.map(|ticks| ticks.div_euclid(per_second) + offset))
+                    .collect()
+            }
+            other => {
+                return Err(DaftError::TypeError(format!(
+                    "dt_strftime is not implemented for type {other}"
+                )))
+            }
+        };
+
+        let formatted = total_seconds
+            .into_iter()
+            .map(|secs| secs.map(|secs| render_strftime(&broken_down_from_seconds(secs), format)));
+        let array = Utf8Array::from((
+            self.name(),
+            Box::new(arrow2::array::Utf8Array::<i64>::from_iter(formatted)),
+        ));
+        Ok(array.into_series())
+    }
+}
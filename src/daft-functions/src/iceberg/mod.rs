@@ -0,0 +1,126 @@
+use common_error::{DaftError, DaftResult};
+use daft_core::prelude::*;
+use daft_dsl::{
+    functions::{ScalarFunction, ScalarUDF},
+    ExprRef,
+};
+use serde::{Deserialize, Serialize};
+
+macro_rules! impl_iceberg_temporal_transform {
+    ($name:ident, $fn_name:ident, $method:ident) => {
+        #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+        pub struct $name;
+
+        #[typetag::serde]
+        impl ScalarUDF for $name {
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+
+            fn name(&self) -> &'static str {
+                stringify!($fn_name)
+            }
+
+            fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+                match inputs {
+                    [input] => {
+                        let field = input.to_field(schema)?;
+                        match &field.dtype {
+                            DataType::Date | DataType::Timestamp(..) => {
+                                Ok(Field::new(field.name, DataType::Int32))
+                            }
+                            _ => Err(DaftError::TypeError(format!(
+                                "{} can only be applied to Date or Timestamp columns, got {field}",
+                                self.name()
+                            ))),
+                        }
+                    }
+                    _ => Err(DaftError::SchemaMismatch(format!(
+                        "Expected 1 input arg, got {}",
+                        inputs.len()
+                    ))),
+                }
+            }
+
+            fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+                match inputs {
+                    [input] => input.$method(),
+                    _ => Err(DaftError::ValueError(format!(
+                        "Expected 1 input arg, got {}",
+                        inputs.len()
+                    ))),
+                }
+            }
+        }
+
+        #[must_use]
+        pub fn $fn_name(input: ExprRef) -> ExprRef {
+            ScalarFunction::new($name {}, vec![input]).into()
+        }
+    };
+}
+
+impl_iceberg_temporal_transform!(IcebergYear, iceberg_year, iceberg_year);
+impl_iceberg_temporal_transform!(IcebergMonth, iceberg_month, iceberg_month);
+impl_iceberg_temporal_transform!(IcebergDay, iceberg_day, iceberg_day);
+impl_iceberg_temporal_transform!(IcebergHour, iceberg_hour, iceberg_hour);
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct IcebergBucket {
+    pub n: i32,
+}
+
+#[typetag::serde]
+impl ScalarUDF for IcebergBucket {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "iceberg_bucket"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let field = input.to_field(schema)?;
+                match &field.dtype {
+                    DataType::Int8
+                    | DataType::Int16
+                    | DataType::Int32
+                    | DataType::Int64
+                    | DataType::UInt8
+                    | DataType::UInt16
+                    | DataType::UInt32
+                    | DataType::UInt64
+                    | DataType::Decimal128(..)
+                    | DataType::Utf8
+                    | DataType::Binary => Ok(Field::new(field.name, DataType::Int32)),
+                    _ => Err(DaftError::TypeError(format!(
+                        "{} can only be applied to integer, decimal, string, or binary columns, got {field}",
+                        self.name()
+                    ))),
+                }
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input] => input.iceberg_bucket(self.n),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[must_use]
+pub fn iceberg_bucket(input: ExprRef, n: i32) -> ExprRef {
+    ScalarFunction::new(IcebergBucket { n }, vec![input]).into()
+}
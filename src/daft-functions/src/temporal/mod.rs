@@ -135,6 +135,62 @@ pub fn dt_time(input: ExprRef) -> ExprRef {
     ScalarFunction::new(Time {}, vec![input]).into()
 }
 
+/// Formats a temporal column to a `Utf8` column via a strftime-style format
+/// string (e.g. `%Y-%m-%d %H:%M:%S`, `%j`), honoring the input's `TimeUnit`.
+/// The directives `%Y %y %m %d %H %I %M %S %p %j %%` are supported; any other
+/// directive (such as `%A`) is copied to the output literally. A
+/// `Timestamp`'s timezone is applied only when it is a fixed UTC offset;
+/// named zones are rendered as UTC.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Strftime {
+    pub format: String,
+}
+
+#[typetag::serde]
+impl ScalarUDF for Strftime {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &'static str {
+        "strftime"
+    }
+
+    fn to_field(&self, inputs: &[ExprRef], schema: &Schema) -> DaftResult<Field> {
+        match inputs {
+            [input] => match input.to_field(schema) {
+                Ok(field) if field.dtype.is_temporal() => {
+                    Ok(Field::new(field.name, DataType::Utf8))
+                }
+                Ok(field) => Err(DaftError::TypeError(format!(
+                    "Expected input to strftime to be temporal, got {}",
+                    field.dtype
+                ))),
+                Err(e) => Err(e),
+            },
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series]) -> DaftResult<Series> {
+        match inputs {
+            [input] => input.dt_strftime(&self.format),
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
+
+#[must_use]
+pub fn dt_strftime(input: ExprRef, format: String) -> ExprRef {
+    ScalarFunction::new(Strftime { format }, vec![input]).into()
+}
+
 #[cfg(test)]
 mod test {
     use std::sync::Arc;
@@ -157,6 +213,12 @@ mod test {
             (Arc::new(Nanosecond), "nanosecond"),
             (Arc::new(Time), "time"),
             (Arc::new(Year), "year"),
+            (
+                Arc::new(Strftime {
+                    format: String::new(),
+                }),
+                "strftime",
+            ),
             (
                 Arc::new(Truncate {
                     interval: String::new(),
@@ -19,10 +19,10 @@
 
 //! [JoinKeySet] for tracking the set of join keys in a plan.
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use daft_dsl::{Expr, ExprRef};
-use indexmap::{Equivalent, IndexSet};
+use indexmap::{Equivalent, IndexMap, IndexSet};
 
 /// Tracks a set of equality Join keys
 ///
@@ -134,6 +134,101 @@ impl JoinKeySet {
     pub fn iter(&self) -> impl Iterator<Item = (&ExprRef, &ExprRef)> {
         self.inner.iter().map(|(l, r)| (l, r))
     }
+
+    /// Groups every expression referenced by this set's pairs into its
+    /// transitive equivalence class: if `(a.x, b.y)` and `(b.y, c.z)` are
+    /// both in the set, `a.x`, `b.y`, and `c.z` all end up in the same
+    /// class even though `a.x = c.z` was never explicitly inserted.
+    ///
+    /// Runs a union-find over the distinct expressions referenced by this
+    /// set's pairs, treating each stored `(left, right)` as an undirected
+    /// edge. A compound expression like `a.x + 5` is just another opaque
+    /// node: it only merges with others through an explicit equality edge,
+    /// never by inspecting its subexpressions.
+    pub fn equivalence_classes(&self) -> Vec<Vec<ExprRef>> {
+        fn find(parent: &mut [usize], x: usize) -> usize {
+            if parent[x] != x {
+                parent[x] = find(parent, parent[x]);
+            }
+            parent[x]
+        }
+
+        fn union(parent: &mut [usize], rank: &mut [usize], a: usize, b: usize) {
+            let ra = find(parent, a);
+            let rb = find(parent, b);
+            if ra == rb {
+                return;
+            }
+            match rank[ra].cmp(&rank[rb]) {
+                std::cmp::Ordering::Less => parent[ra] = rb,
+                std::cmp::Ordering::Greater => parent[rb] = ra,
+                std::cmp::Ordering::Equal => {
+                    parent[rb] = ra;
+                    rank[ra] += 1;
+                }
+            }
+        }
+
+        // Assigns each distinct expression (by value, not by `Arc` identity)
+        // a stable node id, allocating one on first sight.
+        fn node_id<'a>(
+            expr: &'a ExprRef,
+            ids: &mut HashMap<&'a Expr, usize>,
+            exprs: &mut Vec<ExprRef>,
+            parent: &mut Vec<usize>,
+            rank: &mut Vec<usize>,
+        ) -> usize {
+            if let Some(&id) = ids.get(expr.as_ref()) {
+                return id;
+            }
+            let id = exprs.len();
+            ids.insert(expr.as_ref(), id);
+            exprs.push(expr.clone());
+            parent.push(id);
+            rank.push(0);
+            id
+        }
+
+        let mut ids: HashMap<&Expr, usize> = HashMap::new();
+        let mut exprs: Vec<ExprRef> = Vec::new();
+        let mut parent: Vec<usize> = Vec::new();
+        let mut rank: Vec<usize> = Vec::new();
+
+        for (left, right) in &self.inner {
+            let a = node_id(left, &mut ids, &mut exprs, &mut parent, &mut rank);
+            let b = node_id(right, &mut ids, &mut exprs, &mut parent, &mut rank);
+            union(&mut parent, &mut rank, a, b);
+        }
+
+        let mut classes: IndexMap<usize, Vec<ExprRef>> = IndexMap::new();
+        for (i, expr) in exprs.into_iter().enumerate() {
+            let root = find(&mut parent, i);
+            classes.entry(root).or_default().push(expr);
+        }
+
+        classes.into_values().collect()
+    }
+
+    /// Returns a new [`JoinKeySet`] containing every pair implied by this
+    /// set's transitive [`Self::equivalence_classes`], deduplicated against
+    /// pairs already present. The original pairs are retained, in their
+    /// original insert order, as a prefix of the result.
+    pub fn transitive_closure(&self) -> Self {
+        let mut closure = Self::new();
+        for (left, right) in &self.inner {
+            closure.insert(left.as_ref(), right.as_ref());
+        }
+
+        for class in self.equivalence_classes() {
+            for i in 0..class.len() {
+                for j in (i + 1)..class.len() {
+                    closure.insert(class[i].as_ref(), class[j].as_ref());
+                }
+            }
+        }
+
+        closure
+    }
 }
 
 /// Custom comparison operation to avoid copying owned values
@@ -153,4 +248,95 @@ impl<'a> Equivalent<(ExprRef, ExprRef)> for ExprPair<'a> {
     fn equivalent(&self, other: &(ExprRef, ExprRef)) -> bool {
         self.0 == other.0.as_ref() && self.1 == other.1.as_ref()
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_dsl::resolved_col;
+
+    use super::{ExprRef, JoinKeySet};
+
+    fn sorted_names(classes: Vec<Vec<ExprRef>>) -> Vec<Vec<String>> {
+        let mut classes: Vec<Vec<String>> = classes
+            .into_iter()
+            .map(|class| {
+                let mut names: Vec<String> = class.iter().map(|e| e.name().to_string()).collect();
+                names.sort();
+                names
+            })
+            .collect();
+        classes.sort();
+        classes
+    }
+
+    /// `a.x = b.y AND b.y = c.z` should infer the transitive `a.x = c.z`
+    /// equivalence even though it was never explicitly inserted.
+    #[test]
+    fn test_equivalence_classes_merges_transitively() {
+        let mut set = JoinKeySet::new();
+        set.insert(&resolved_col("ax"), &resolved_col("by"));
+        set.insert(&resolved_col("by"), &resolved_col("cz"));
+
+        let classes = sorted_names(set.equivalence_classes());
+        assert_eq!(classes, vec![vec!["ax", "by", "cz"]]);
+    }
+
+    /// Two pairs that share no edge stay in separate classes.
+    #[test]
+    fn test_equivalence_classes_does_not_merge_disjoint_pairs() {
+        let mut set = JoinKeySet::new();
+        set.insert(&resolved_col("ax"), &resolved_col("by"));
+        set.insert(&resolved_col("cz"), &resolved_col("dw"));
+
+        let classes = sorted_names(set.equivalence_classes());
+        assert_eq!(classes, vec![vec!["ax", "by"], vec!["cz", "dw"]]);
+    }
+
+    /// A compound expression like `a.x + 5` only merges through an explicit
+    /// equality edge, never by inspecting its subexpressions.
+    #[test]
+    fn test_equivalence_classes_treats_compound_expr_as_opaque() {
+        let compound = resolved_col("ax").add(daft_dsl::lit(5));
+        let mut set = JoinKeySet::new();
+        set.insert(&compound, &resolved_col("by"));
+
+        let classes = set.equivalence_classes();
+        assert_eq!(classes.len(), 1);
+        assert_eq!(classes[0].len(), 2);
+        // The compound expression itself is retained as a single atomic
+        // node, not decomposed into `ax` and the literal `5`.
+        assert!(classes[0].iter().any(|e| e.as_ref() == compound.as_ref()));
+    }
+
+    /// `transitive_closure` emits every pair within a class, retains the
+    /// original pairs as a prefix (in insert order), and doesn't duplicate a
+    /// pair that's already present.
+    #[test]
+    fn test_transitive_closure_emits_all_pairs_within_a_class() {
+        let mut set = JoinKeySet::new();
+        set.insert(&resolved_col("ax"), &resolved_col("by"));
+        set.insert(&resolved_col("by"), &resolved_col("cz"));
+
+        let closure = set.transitive_closure();
+
+        assert!(closure.contains(&resolved_col("ax"), &resolved_col("by")));
+        assert!(closure.contains(&resolved_col("by"), &resolved_col("cz")));
+        assert!(closure.contains(&resolved_col("ax"), &resolved_col("cz")));
+        // Only the 3 distinct pairs within the single 3-element class.
+        assert_eq!(closure.len(), 3);
+    }
+
+    /// `transitive_closure` never merges across classes that share no edge.
+    #[test]
+    fn test_transitive_closure_does_not_cross_disjoint_classes() {
+        let mut set = JoinKeySet::new();
+        set.insert(&resolved_col("ax"), &resolved_col("by"));
+        set.insert(&resolved_col("cz"), &resolved_col("dw"));
+
+        let closure = set.transitive_closure();
+
+        assert!(!closure.contains(&resolved_col("ax"), &resolved_col("cz")));
+        assert!(!closure.contains(&resolved_col("by"), &resolved_col("dw")));
+        assert_eq!(closure.len(), 2);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,152 @@
+use std::{collections::HashMap, sync::Arc};
+
+use common_error::DaftResult;
+use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
+use daft_dsl::resolved_col;
+use indexmap::IndexSet;
+
+use super::OptimizerRule;
+use crate::{ops::Project, source_info::SourceInfo, LogicalPlan, LogicalPlanRef};
+
+/// Makes column pruning safe in the presence of a cache entry
+/// (`SourceInfo::InMemory`) read from more than one place in the plan.
+///
+/// Pruning a node's upstream to exactly what its one parent needs is only
+/// sound when every node has exactly one parent. A cache entry breaks that
+/// assumption: the same materialized partition set (identified by
+/// `InMemoryInfo::cache_key`) may be read by several `Source` nodes
+/// scattered through the plan (e.g. a CTE used twice), each independently
+/// deciding which columns it needs. Pruning each occurrence to only its own
+/// requirement would read the underlying cache entry with different,
+/// inconsistent column sets.
+///
+/// This rule runs before [`super::PushDownProjection`]: in a first
+/// traversal it unions the required columns across every occurrence of
+/// each `cache_key`; in a second traversal it inserts a [`Project`]
+/// selecting that union directly above every occurrence of the `Source`.
+/// `PushDownProjection`'s regular per-parent pushdown then narrows each
+/// occurrence's new `Project` down to its own subset, without ever pruning
+/// the shared `Source` itself to less than the global union. A plan with no
+/// `SourceInfo::InMemory` source is left unchanged. A cache entry read only
+/// once is not treated differently: its `Source` also gets a `Project` when
+/// its one parent needs fewer columns than it produces.
+#[derive(Default, Debug)]
+pub struct CacheAwareProjectionPushdown {}
+
+impl CacheAwareProjectionPushdown {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn collect_required_columns(
+        &self,
+        plan: &LogicalPlanRef,
+        required_by_cache_key: &mut HashMap<String, IndexSet<String>>,
+    ) {
+        let children = plan.arc_children();
+        let required_columns = plan.required_columns();
+        for (child, required) in children.iter().zip(required_columns.iter()) {
+            if let LogicalPlan::Source(source) = child.as_ref()
+                && let SourceInfo::InMemory(info) = source.source_info.as_ref()
+            {
+                required_by_cache_key
+                    .entry(info.cache_key.clone())
+                    .or_default()
+                    .extend(required.iter().cloned());
+            }
+            self.collect_required_columns(child, required_by_cache_key);
+        }
+    }
+
+    fn insert_union_projection(
+        &self,
+        plan: LogicalPlanRef,
+        required_by_cache_key: &HashMap<String, IndexSet<String>>,
+    ) -> DaftResult<Transformed<LogicalPlanRef>> {
+        if let LogicalPlan::Source(source) = plan.as_ref()
+            && let SourceInfo::InMemory(info) = source.source_info.as_ref()
+        {
+            let union_columns = &required_by_cache_key[&info.cache_key];
+            if union_columns.len() < plan.schema().names().len() {
+                let pushdown_column_exprs = union_columns
+                    .iter()
+                    .map(|name| resolved_col(name.as_str()))
+                    .collect::<Vec<_>>();
+                let new_plan: LogicalPlan =
+                    Project::try_new(plan.clone(), pushdown_column_exprs)?.into();
+                // Jump so the traversal doesn't descend into the new
+                // `Project` and wrap the same `Source` again.
+                return Ok(Transformed::new(
+                    new_plan.into(),
+                    true,
+                    TreeNodeRecursion::Jump,
+                ));
+            }
+        }
+        Ok(Transformed::no(plan))
+    }
+}
+
+impl OptimizerRule for CacheAwareProjectionPushdown {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        let mut required_by_cache_key: HashMap<String, IndexSet<String>> = HashMap::new();
+        self.collect_required_columns(&plan, &mut required_by_cache_key);
+
+        if required_by_cache_key.is_empty() {
+            return Ok(Transformed::no(plan));
+        }
+
+        plan.transform_down(|node| self.insert_union_projection(node, &required_by_cache_key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::unresolved_col;
+
+    use super::CacheAwareProjectionPushdown;
+    use crate::{
+        optimization::{
+            optimizer::{RuleBatch, RuleExecutionStrategy},
+            test::assert_optimized_plan_with_rules_eq,
+        },
+        test::{dummy_scan_node, dummy_scan_operator},
+        LogicalPlan,
+    };
+
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![RuleBatch::new(
+                vec![Box::new(CacheAwareProjectionPushdown::new())],
+                RuleExecutionStrategy::Once,
+            )],
+        )
+    }
+
+    /// With no `SourceInfo::InMemory` anywhere in the plan (the usual case,
+    /// since this rule only exists to protect cache entries read from
+    /// multiple places), the rule degrades to a no-op rather than
+    /// insert any projection.
+    #[test]
+    fn test_no_cache_source_is_a_no_op() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]);
+        let plan = dummy_scan_node(scan_op)
+            .select(vec![unresolved_col("a")])?
+            .build();
+
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+use std::{collections::HashMap, sync::Arc};
+
+use common_error::DaftResult;
+use common_treenode::{Transformed, TreeNode, TreeNodeRecursion};
+use daft_dsl::{is_udf, optimization::requires_computation, resolved_col, Expr, ExprRef};
+use indexmap::IndexMap;
+
+use super::OptimizerRule;
+use crate::{ops::Project, LogicalPlan};
+
+/// Eliminates duplicate computation within a single [`Project`]'s expression
+/// list by materializing any subexpression referenced two or more times in a
+/// new upstream [`Project`] under a synthetic column name, then rewriting the
+/// original expressions to reference that column instead.
+///
+/// `PushDownProjection`'s merge path refuses to merge an upstream projection
+/// into a downstream one whenever one of the upstream's computation-required
+/// columns is referenced more than once downstream, to avoid duplicating the
+/// computation. Running this rule first extracts any such duplicated
+/// subexpression into its own column, so every expensive expression ends up
+/// referenced exactly once and the merge can proceed safely.
+#[derive(Default, Debug)]
+pub struct CommonSubexprEliminate {}
+
+impl CommonSubexprEliminate {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn try_optimize_project(
+        &self,
+        projection: &Project,
+        plan: Arc<LogicalPlan>,
+    ) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        // Count occurrences of every non-trivial, side-effect-free
+        // subexpression across the whole projection list, keyed by
+        // structural equality so `a + 1` appearing twice under different
+        // `Arc`s is still recognized as the same subexpression.
+        let mut counts: IndexMap<&Expr, (ExprRef, usize)> = IndexMap::new();
+        for expr in &projection.projection {
+            expr.apply(|node| {
+                if requires_computation(node) && !node.exists(is_udf) {
+                    counts
+                        .entry(node.as_ref())
+                        .and_modify(|(_, count)| *count += 1)
+                        .or_insert_with(|| (node.clone(), 1));
+                }
+                Ok(TreeNodeRecursion::Continue)
+            })?;
+        }
+
+        let duplicated: Vec<ExprRef> = counts
+            .into_values()
+            .filter(|(_, count)| *count >= 2)
+            .map(|(expr, _)| expr)
+            .collect();
+
+        if duplicated.is_empty() {
+            return Ok(Transformed::no(plan));
+        }
+
+        // Materialize each duplicated subexpression once, under a synthetic
+        // column name, in a new Project inserted directly above the input.
+        let mut replacements: HashMap<&Expr, ExprRef> = HashMap::new();
+        let mut subexprs_projection = Vec::with_capacity(duplicated.len());
+        for (i, expr) in duplicated.iter().enumerate() {
+            let synthetic_name = format!("__common_subexpr_{i}");
+            subexprs_projection.push(expr.clone().alias(synthetic_name.as_str()));
+            replacements.insert(expr.as_ref(), resolved_col(synthetic_name));
+        }
+        // Pass through the original input columns too, since the original
+        // projection (and any of its own non-extracted subexpressions) may
+        // still depend on them directly.
+        for name in projection.input.schema().names() {
+            subexprs_projection.push(resolved_col(name.as_str()));
+        }
+
+        let new_upstream: LogicalPlan =
+            Project::try_new(projection.input.clone(), subexprs_projection)?.into();
+
+        let new_projection_exprs = projection
+            .projection
+            .iter()
+            .map(|expr| {
+                Ok(expr
+                    .clone()
+                    .transform_down(|node| {
+                        if let Some(replacement) = replacements.get(node.as_ref()) {
+                            Ok(Transformed::yes(replacement.clone()))
+                        } else {
+                            Ok(Transformed::no(node))
+                        }
+                    })?
+                    .data)
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        let new_plan: LogicalPlan =
+            Project::try_new(new_upstream.into(), new_projection_exprs)?.into();
+
+        Ok(Transformed::yes(new_plan.into()))
+    }
+}
+
+impl OptimizerRule for CommonSubexprEliminate {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        plan.transform_down(|node| match node.as_ref() {
+            LogicalPlan::Project(projection) => {
+                self.try_optimize_project(projection, node.clone())
+            }
+            _ => Ok(Transformed::no(node)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{lit, resolved_col, unresolved_col};
+
+    use super::CommonSubexprEliminate;
+    use crate::{
+        ops::Project,
+        optimization::{
+            optimizer::{RuleBatch, RuleExecutionStrategy},
+            test::assert_optimized_plan_with_rules_eq,
+        },
+        test::{dummy_scan_node, dummy_scan_operator},
+        LogicalPlan,
+    };
+
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![RuleBatch::new(
+                vec![Box::new(CommonSubexprEliminate::new())],
+                RuleExecutionStrategy::Once,
+            )],
+        )
+    }
+
+    /// A subexpression repeated within a single projected expression gets
+    /// materialized once in a new upstream `Project` under a synthetic
+    /// column name, and both occurrences are rewritten to reference it.
+    #[test]
+    fn test_extracts_duplicated_subexpression() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![Field::new("a", DataType::Int64)]);
+
+        let a_plus_1 = unresolved_col("a").add(lit(1));
+        let expr = a_plus_1.clone().add(a_plus_1.clone()).alias("x");
+        let plan = dummy_scan_node(scan_op.clone())
+            .select(vec![expr])?
+            .build();
+
+        let new_upstream: LogicalPlan = Project::try_new(
+            dummy_scan_node(scan_op).build(),
+            vec![
+                a_plus_1.alias("__common_subexpr_0"),
+                resolved_col("a"),
+            ],
+        )?
+        .into();
+        let expected = LogicalPlan::Project(Project::try_new(
+            new_upstream.into(),
+            vec![resolved_col("__common_subexpr_0")
+                .add(resolved_col("__common_subexpr_0"))
+                .alias("x")],
+        )?)
+        .arced();
+
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// A projection with no subexpression referenced more than once is left
+    /// untouched.
+    #[test]
+    fn test_no_duplicated_subexpression_is_a_no_op() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]);
+        let proj = vec![
+            unresolved_col("a").add(lit(1)),
+            unresolved_col("b").add(lit(2)),
+        ];
+        let plan = dummy_scan_node(scan_op).select(proj)?.build();
+
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_treenode::{Transformed, TreeNode};
+
+use super::{functional_dependencies::derive_functional_dependencies, OptimizerRule};
+use crate::LogicalPlan;
+
+/// Removes a `Distinct` over all of its input's columns when the input's own
+/// functional dependencies already guarantee every row is unique.
+///
+/// Concretely: a `Distinct` with `columns: None` deduplicates on the full
+/// column set, which is a superset of any key, so this fires whenever the
+/// input has any known key (a `Dependency::Single` dependency) -- e.g. a
+/// `Distinct` directly atop an `Aggregate`, keyed on its group-by columns,
+/// or atop another `Distinct` with an explicit column list. Because [`derive_functional_dependencies`] recursively
+/// propagates through `Project` and `Join` rather than only matching
+/// `Aggregate`/`Distinct` directly, a `Project` or `Join` sitting between
+/// the `Distinct` and the node that actually establishes the key doesn't
+/// defeat this. In that case the distinct can't remove any rows and is
+/// redundant.
+#[derive(Default, Debug)]
+pub struct EliminateDistinct {}
+
+impl EliminateDistinct {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for EliminateDistinct {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        plan.transform_down(|node| {
+            let LogicalPlan::Distinct(distinct) = node.as_ref() else {
+                return Ok(Transformed::no(node));
+            };
+            if distinct.columns.is_some() {
+                return Ok(Transformed::no(node));
+            }
+
+            let num_fields = distinct.input.schema().names().len();
+            let all_indices: Vec<usize> = (0..num_fields).collect();
+            let deps = derive_functional_dependencies(distinct.input.as_ref());
+            if deps.is_superkey(&all_indices) {
+                Ok(Transformed::yes(distinct.input.clone()))
+            } else {
+                Ok(Transformed::no(node))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::unresolved_col;
+
+    use super::EliminateDistinct;
+    use crate::{
+        ops::{Aggregate, Distinct, Project},
+        optimization::{
+            optimizer::{RuleBatch, RuleExecutionStrategy},
+            test::assert_optimized_plan_with_rules_eq,
+        },
+        test::{dummy_scan_node, dummy_scan_operator},
+        LogicalPlan,
+    };
+
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![RuleBatch::new(
+                vec![Box::new(EliminateDistinct::new())],
+                RuleExecutionStrategy::Once,
+            )],
+        )
+    }
+
+    /// A `Distinct` over all columns directly atop an `Aggregate`'s own
+    /// group-by keys can't remove any rows -- the group-by already
+    /// guarantees uniqueness -- so it's dropped entirely.
+    #[test]
+    fn test_eliminates_distinct_over_aggregate_output() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]);
+        let agg = vec![unresolved_col("b").mean()];
+        let group_by = vec![unresolved_col("a")];
+        let aggregate = dummy_scan_node(scan_op).aggregate(agg, group_by)?.build();
+        let plan: LogicalPlan = Distinct::try_new(aggregate.clone(), None)?.into();
+
+        assert_optimized_plan_eq(plan.arced(), aggregate)?;
+        Ok(())
+    }
+
+    /// A `Project` that merely reorders an `Aggregate`'s output columns
+    /// doesn't defeat the rule, since functional dependencies are propagated
+    /// through `Project` rather than only matched against the immediate
+    /// child.
+    #[test]
+    fn test_eliminates_distinct_through_reordering_project() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]);
+        let agg = vec![unresolved_col("b").mean()];
+        let group_by = vec![unresolved_col("a")];
+        let aggregate = dummy_scan_node(scan_op).aggregate(agg, group_by)?.build();
+        let reordered: LogicalPlan = Project::try_new(
+            aggregate,
+            vec![unresolved_col("a"), unresolved_col("b")],
+        )?
+        .into();
+        let plan: LogicalPlan = Distinct::try_new(reordered.clone().into(), None)?.into();
+
+        assert_optimized_plan_eq(plan.arced(), reordered.arced())?;
+        Ok(())
+    }
+
+    /// A `Distinct` over all columns atop a plan with no known key (e.g. a
+    /// bare scan) isn't provably redundant, so it's left untouched.
+    #[test]
+    fn test_does_not_eliminate_distinct_with_no_known_key() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![Field::new("a", DataType::Int64)]);
+        let scan = dummy_scan_node(scan_op).build();
+        let plan: LogicalPlan = Distinct::try_new(scan, None)?.into();
+        let plan = plan.arced();
+
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+
+    /// A `Distinct` with an explicit column list is never touched by this
+    /// rule -- only the all-columns form is eligible.
+    #[test]
+    fn test_does_not_eliminate_distinct_with_explicit_columns() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]);
+        let agg = vec![unresolved_col("b").mean()];
+        let group_by = vec![unresolved_col("a")];
+        let aggregate = dummy_scan_node(scan_op).aggregate(agg, group_by)?.build();
+        let plan: LogicalPlan =
+            Distinct::try_new(aggregate, Some(vec![unresolved_col("a")]))?.into();
+        let plan = plan.arced();
+
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_treenode::{Transformed, TreeNode};
+
+use super::{push_down_projection::try_merge_or_drop_project, OptimizerRule};
+use crate::{ops::Project, LogicalPlan};
+
+/// Shrinks the plan's `Project` nodes before column pruning runs: merges two
+/// directly adjacent `Project`s into one, and drops a `Project` that's a
+/// pure passthrough of all of its child's columns in the same order.
+///
+/// This reuses the exact no-op-drop and projection-merge logic
+/// [`super::PushDownProjection`] applies as the first step of its own
+/// per-node pass, but runs it as an independent, earlier pass that doesn't
+/// depend on required-columns propagation through the rest of the plan.
+/// Running it before `PushDownProjection` means the required-columns
+/// computation that pass does at every node downstream starts from an
+/// already-minimal set of `Project`s, instead of rediscovering the same
+/// merges and drops itself while it prunes.
+///
+/// A `Project` that's a pure *rename* (rather than identity passthrough) of
+/// every child column is deliberately not dropped outright here: its output
+/// names are what everything above it references, so removing it would
+/// silently revert the subtree's visible schema to the old names. That case
+/// is instead handled by the merge step, which folds the rename into
+/// whatever `Project` sits directly above it.
+#[derive(Default, Debug)]
+pub struct EliminateRedundantProjections {}
+
+impl EliminateRedundantProjections {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl OptimizerRule for EliminateRedundantProjections {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        plan.transform_up(|node| match node.as_ref() {
+            LogicalPlan::Project(projection) => {
+                try_merge_or_drop_project(projection, node.clone())
+            }
+            _ => Ok(Transformed::no(node)),
+        })
+    }
+}
@@ -0,0 +1,317 @@
+//! Derives a [`LogicalPlan`] node's functional dependencies from its
+//! children's, rather than only from the node's own immediate shape.
+//!
+//! [`derive_functional_dependencies`] is shared by [`super::eliminate_distinct`]
+//! and [`super::prune_redundant_groupby_keys`] so both rules see the same
+//! dependencies regardless of what sits between the node they're optimizing
+//! and the `Aggregate`/`Distinct` that actually establishes a key -- e.g. a
+//! `Project` that merely reorders or drops columns, or either side of a
+//! `Join`, no longer silently defeats the rule the way a bare two-case match
+//! on the immediate node would.
+
+use std::collections::HashMap;
+
+use daft_schema::functional_dependencies::{
+    Dependency, FunctionalDependence, FunctionalDependencies,
+};
+
+use crate::{
+    ops::{Aggregate, Distinct, Join, Project},
+    LogicalPlan,
+};
+
+/// Returns the column name if `expr` is a bare resolved-column reference.
+pub(super) fn bare_column_name(expr: &daft_dsl::ExprRef) -> Option<String> {
+    use daft_dsl::{Column, Expr, ResolvedColumn};
+    match expr.as_ref() {
+        Expr::Column(Column::Resolved(ResolvedColumn::Basic(name))) => Some(name.to_string()),
+        _ => None,
+    }
+}
+
+/// Derives the functional dependencies a plan node's own output guarantees.
+///
+/// Only the shapes this tree can ground concretely are covered: an
+/// `Aggregate`'s group-by keys determine every aggregated output by
+/// construction; a `Distinct` over an explicit column list makes that list
+/// a key for its own output; a `Project` restates its input's dependencies
+/// in terms of whatever output positions still carry the determining
+/// columns unchanged; and a `Join` carries each side's own dependencies
+/// through at their (possibly offset) position in the concatenated output
+/// schema. A `Filter` keeps its input's dependencies unchanged, since it
+/// only removes rows. Everything else -- notably `Source`, whose scan metadata carries
+/// no primary-key/unique-constraint information in this codebase -- falls
+/// back to no dependencies. That's conservative: it only forgoes some
+/// eliminations, never an unsound one.
+pub(super) fn derive_functional_dependencies(plan: &LogicalPlan) -> FunctionalDependencies {
+    match plan {
+        LogicalPlan::Aggregate(aggregate) => derive_from_aggregate(aggregate),
+        LogicalPlan::Distinct(distinct) => derive_from_distinct(plan, distinct),
+        LogicalPlan::Project(project) => propagate_through_project(project),
+        LogicalPlan::Join(join) => propagate_through_join(join),
+        LogicalPlan::Filter(filter) => derive_functional_dependencies(filter.input.as_ref()),
+        _ => FunctionalDependencies::empty(),
+    }
+}
+
+fn derive_from_aggregate(aggregate: &Aggregate) -> FunctionalDependencies {
+    let num_fields = aggregate.aggregations.len() + aggregate.groupby.len();
+    let source_indices = (aggregate.aggregations.len()..num_fields).collect();
+    FunctionalDependencies::new_from_constraint(source_indices, num_fields)
+}
+
+fn derive_from_distinct(plan: &LogicalPlan, distinct: &Distinct) -> FunctionalDependencies {
+    match &distinct.columns {
+        Some(columns) => {
+            let names = plan.schema().names();
+            let source_indices = columns
+                .iter()
+                .filter_map(|e| names.iter().position(|n| n == e.name()))
+                .collect();
+            FunctionalDependencies::new_from_constraint(source_indices, names.len())
+        }
+        // A distinct over every column guarantees the full row is unique,
+        // which isn't a useful (proper-subset) key for anyone downstream to
+        // exploit.
+        None => FunctionalDependencies::empty(),
+    }
+}
+
+/// Restates `project.input`'s dependencies in terms of `project`'s output
+/// positions, dropping any dependency whose `source_indices` don't all
+/// survive as an unchanged (bare column reference) output.
+fn propagate_through_project(project: &Project) -> FunctionalDependencies {
+    let input_deps = derive_functional_dependencies(project.input.as_ref());
+    if input_deps.is_empty() {
+        return FunctionalDependencies::empty();
+    }
+
+    let input_names = project.input.schema().names();
+    let mut old_to_new: HashMap<usize, usize> = HashMap::new();
+    for (new_idx, expr) in project.projection.iter().enumerate() {
+        if let Some(name) = bare_column_name(expr) {
+            if let Some(old_idx) = input_names.iter().position(|n| *n == name) {
+                old_to_new.entry(old_idx).or_insert(new_idx);
+            }
+        }
+    }
+
+    let mut deps = FunctionalDependencies::empty();
+    for dep in input_deps.iter() {
+        let Some(new_source) = dep
+            .source_indices
+            .iter()
+            .map(|i| old_to_new.get(i).copied())
+            .collect::<Option<Vec<usize>>>()
+        else {
+            // A column that determines this dependency was dropped by the
+            // projection, so it can no longer be restated in the output.
+            continue;
+        };
+        let new_dependent: Vec<usize> = dep
+            .dependent_indices
+            .iter()
+            .filter_map(|i| old_to_new.get(i).copied())
+            .collect();
+        // A key (`Dependency::Single`) still determines the whole output
+        // even when none of its recorded dependents survive.
+        if !new_dependent.is_empty() || dep.mode == Dependency::Single {
+            deps.push(FunctionalDependence::new(
+                new_source,
+                new_dependent,
+                dep.mode,
+            ));
+        }
+    }
+    deps
+}
+
+/// Carries each side of a `Join`'s own dependencies through to the combined
+/// output, offsetting the right side's indices past the left side's width
+/// to match the concatenated output schema. A join doesn't itself establish
+/// new dependencies between the two sides (that would require reasoning
+/// about the join condition's cardinality, which isn't attempted here).
+fn propagate_through_join(join: &Join) -> FunctionalDependencies {
+    let left_width = join.left.schema().names().len();
+
+    let mut deps = FunctionalDependencies::empty();
+    for dep in derive_functional_dependencies(join.left.as_ref()).iter() {
+        deps.push(dep.clone());
+    }
+    for dep in derive_functional_dependencies(join.right.as_ref()).iter() {
+        deps.push(FunctionalDependence::new(
+            dep.source_indices.iter().map(|i| i + left_width).collect(),
+            dep.dependent_indices
+                .iter()
+                .map(|i| i + left_width)
+                .collect(),
+            dep.mode,
+        ));
+    }
+    deps
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_core::prelude::*;
+    use daft_dsl::unresolved_col;
+
+    use super::derive_functional_dependencies;
+    use crate::{
+        ops::{Aggregate, Distinct, Join, Project},
+        test::{dummy_scan_node, dummy_scan_operator},
+        LogicalPlan,
+    };
+
+    /// An `Aggregate`'s group-by keys determine every aggregated output.
+    #[test]
+    fn test_derive_from_aggregate() {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]);
+        let plan: LogicalPlan = Aggregate::try_new(
+            dummy_scan_node(scan_op).build(),
+            vec![unresolved_col("a").mean()],
+            vec![unresolved_col("b")],
+        )
+        .unwrap()
+        .into();
+
+        let deps = derive_functional_dependencies(&plan);
+        // Output schema is [mean(a), b]; the group-by key at position 1
+        // determines the aggregated output at position 0.
+        assert!(deps.is_superkey(&[1]));
+        assert!(!deps.is_superkey(&[0]));
+    }
+
+    /// A `Distinct` over an explicit column list makes that list a key for
+    /// its own output.
+    #[test]
+    fn test_derive_from_distinct_with_explicit_columns() {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]);
+        let plan: LogicalPlan = Distinct::try_new(
+            dummy_scan_node(scan_op).build(),
+            Some(vec![unresolved_col("a")]),
+        )
+        .unwrap()
+        .into();
+
+        let deps = derive_functional_dependencies(&plan);
+        assert!(deps.is_superkey(&[0]));
+    }
+
+    /// A `Distinct` over every column (no explicit column list) guarantees
+    /// the full row is unique, which isn't a useful key for anyone
+    /// downstream to exploit.
+    #[test]
+    fn test_derive_from_distinct_over_all_columns_is_empty() {
+        let scan_op = dummy_scan_operator(vec![Field::new("a", DataType::Int64)]);
+        let plan: LogicalPlan = Distinct::try_new(dummy_scan_node(scan_op).build(), None)
+            .unwrap()
+            .into();
+
+        assert!(derive_functional_dependencies(&plan).is_empty());
+    }
+
+    /// A `Project` that passes a determining column through unchanged
+    /// restates the dependency in terms of the new output positions.
+    #[test]
+    fn test_propagate_through_project_restates_surviving_dependency() {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]);
+        let aggregate: LogicalPlan = Aggregate::try_new(
+            dummy_scan_node(scan_op).build(),
+            vec![unresolved_col("a").mean()],
+            vec![unresolved_col("b")],
+        )
+        .unwrap()
+        .into();
+        // Reorder so the group-by key lands at position 0.
+        let plan: LogicalPlan = Project::try_new(
+            aggregate.into(),
+            vec![unresolved_col("b"), unresolved_col("a").mean()],
+        )
+        .unwrap()
+        .into();
+
+        let deps = derive_functional_dependencies(&plan);
+        assert!(deps.is_superkey(&[0]));
+    }
+
+    /// A `Project` that drops the column a dependency is keyed on can no
+    /// longer restate that dependency in its output.
+    #[test]
+    fn test_propagate_through_project_drops_dependency_on_dropped_column() {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]);
+        let aggregate: LogicalPlan = Aggregate::try_new(
+            dummy_scan_node(scan_op).build(),
+            vec![unresolved_col("a").mean()],
+            vec![unresolved_col("b")],
+        )
+        .unwrap()
+        .into();
+        // Drop the group-by key `b` entirely.
+        let plan: LogicalPlan =
+            Project::try_new(aggregate.into(), vec![unresolved_col("a").mean()])
+                .unwrap()
+                .into();
+
+        assert!(derive_functional_dependencies(&plan).is_empty());
+    }
+
+    /// A `Join` carries each side's own dependencies through, offsetting the
+    /// right side's indices past the left side's width.
+    #[test]
+    fn test_propagate_through_join_offsets_right_side_indices() {
+        let left_scan = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]);
+        let left: LogicalPlan = Distinct::try_new(
+            dummy_scan_node(left_scan).build(),
+            Some(vec![unresolved_col("a")]),
+        )
+        .unwrap()
+        .into();
+
+        let right_scan = dummy_scan_operator(vec![
+            Field::new("x", DataType::Int64),
+            Field::new("y", DataType::Int64),
+        ]);
+        let right: LogicalPlan = Distinct::try_new(
+            dummy_scan_node(right_scan).build(),
+            Some(vec![unresolved_col("y")]),
+        )
+        .unwrap()
+        .into();
+
+        let plan: LogicalPlan = Join::try_new(
+            left.into(),
+            right.into(),
+            vec![unresolved_col("a")],
+            vec![unresolved_col("x")],
+            None,
+            JoinType::Inner,
+            None,
+        )
+        .unwrap()
+        .into();
+
+        let deps = derive_functional_dependencies(&plan);
+        // Left's key (position 0 in its own schema) keeps its position; the
+        // right's key (position 1 in its own schema) lands at 1 + 2 == 3 in
+        // the concatenated [a, b, x, y] output.
+        assert!(deps.is_superkey(&[0]));
+        assert!(deps.is_superkey(&[3]));
+        assert!(!deps.is_superkey(&[2]));
+    }
+}
@@ -0,0 +1,229 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_treenode::{Transformed, TreeNode};
+use daft_dsl::{is_udf, optimization::get_required_columns, resolved_col, ExprRef};
+use daft_schema::functional_dependencies::{Dependency, FunctionalDependence, FunctionalDependencies};
+use indexmap::IndexSet;
+
+use super::{functional_dependencies::bare_column_name, OptimizerRule};
+use crate::{
+    ops::{Aggregate, Project},
+    LogicalPlan,
+};
+
+/// Drops a GROUP BY key from `Aggregate::groupby` when it's functionally
+/// determined by another, simpler key already in the same group-by list --
+/// narrowing the hash-aggregate key -- and reconstructs it downstream via a
+/// `Project` so nothing observes a schema change.
+///
+/// A key `b` is considered determined by key `a` when `a` is a bare column
+/// reference, `b`'s value is a deterministic expression (no UDFs) of
+/// exactly that one column, and `b` isn't itself that column. In that case,
+/// every row within a group shares the same `a` value, so `b` is constant
+/// within the group too: e.g. `GROUP BY primary_key, derived_attr` where
+/// `derived_attr` is some pure function of `primary_key` only needs
+/// `primary_key` in the hash key.
+#[derive(Default, Debug)]
+pub struct PruneRedundantGroupByKeys {}
+
+impl PruneRedundantGroupByKeys {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn try_optimize_aggregate(
+        &self,
+        aggregate: &Aggregate,
+        plan: Arc<LogicalPlan>,
+    ) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        if aggregate.groupby.len() < 2 {
+            return Ok(Transformed::no(plan));
+        }
+
+        let source_columns: IndexSet<String> = aggregate
+            .groupby
+            .iter()
+            .filter_map(bare_column_name)
+            .collect();
+
+        let mut deps = FunctionalDependencies::empty();
+        for (i, key) in aggregate.groupby.iter().enumerate() {
+            if bare_column_name(key).is_some() || key.exists(is_udf) {
+                continue;
+            }
+            let required = get_required_columns(key);
+            if let [only] = required.as_slice()
+                && source_columns.contains(only)
+                && *only != key.name()
+            {
+                let source_idx = aggregate
+                    .groupby
+                    .iter()
+                    .position(|k| bare_column_name(k).as_deref() == Some(only.as_str()))
+                    .expect("source_columns only contains names of bare groupby keys");
+                deps.push(FunctionalDependence::new(
+                    vec![source_idx],
+                    vec![i],
+                    Dependency::Multi,
+                ));
+            }
+        }
+
+        if deps.is_empty() {
+            return Ok(Transformed::no(plan));
+        }
+        debug_assert!(deps.is_valid(aggregate.groupby.len()));
+
+        // `deps` is the actual source of truth for which positions are
+        // redundant -- rather than being discarded once it's validated,
+        // `redundant_indices` is what drives which keys get dropped below.
+        let all_indices: Vec<usize> = (0..aggregate.groupby.len()).collect();
+        let redundant_positions = deps.redundant_indices(&all_indices);
+
+        let dropped_keys: Vec<ExprRef> = redundant_positions
+            .iter()
+            .map(|&i| aggregate.groupby[i].clone())
+            .collect();
+        let new_groupby: Vec<ExprRef> = aggregate
+            .groupby
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !redundant_positions.contains(i))
+            .map(|(_, e)| e.clone())
+            .collect();
+
+        let new_aggregate: LogicalPlan = Aggregate::try_new(
+            aggregate.input.clone(),
+            aggregate.aggregations.clone(),
+            new_groupby,
+        )?
+        .into();
+
+        // Reconstruct the original output schema: the aggregation outputs
+        // pass through unchanged, and each original groupby key is either
+        // passed through (if kept) or recomputed from its still-present
+        // source column (if dropped), in its original position.
+        let mut reconstructed: Vec<ExprRef> = aggregate
+            .aggregations
+            .iter()
+            .map(|e| resolved_col(e.name()))
+            .collect();
+        reconstructed.extend(aggregate.groupby.iter().enumerate().map(|(i, key)| {
+            if redundant_positions.contains(&i) {
+                dropped_keys[redundant_positions.iter().position(|&p| p == i).unwrap()].clone()
+            } else {
+                resolved_col(key.name())
+            }
+        }));
+
+        let new_plan: LogicalPlan = Project::try_new(new_aggregate.into(), reconstructed)?.into();
+        Ok(Transformed::yes(new_plan.into()))
+    }
+}
+
+impl OptimizerRule for PruneRedundantGroupByKeys {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        plan.transform_down(|node| match node.as_ref() {
+            LogicalPlan::Aggregate(aggregate) => {
+                self.try_optimize_aggregate(aggregate, node.clone())
+            }
+            _ => Ok(Transformed::no(node)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::{resolved_col, unresolved_col};
+
+    use super::PruneRedundantGroupByKeys;
+    use crate::{
+        ops::Project,
+        optimization::{
+            optimizer::{RuleBatch, RuleExecutionStrategy},
+            test::assert_optimized_plan_with_rules_eq,
+        },
+        test::{dummy_scan_node, dummy_scan_operator},
+        LogicalPlan,
+    };
+
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![RuleBatch::new(
+                vec![Box::new(PruneRedundantGroupByKeys::new())],
+                RuleExecutionStrategy::Once,
+            )],
+        )
+    }
+
+    /// A group-by key that's a pure function of another, simpler key
+    /// already in the same group-by list is dropped from the hash-aggregate
+    /// key and reconstructed downstream via a `Project`.
+    #[test]
+    fn test_drops_groupby_key_determined_by_another() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]);
+        let agg = vec![unresolved_col("b").mean()];
+        let derived = unresolved_col("a").add(daft_dsl::lit(1)).alias("a_plus_1");
+        let group_by = vec![unresolved_col("a"), derived.clone()];
+        let plan = dummy_scan_node(scan_op.clone())
+            .aggregate(agg.clone(), group_by)?
+            .build();
+
+        let new_aggregate = dummy_scan_node(scan_op)
+            .aggregate(agg, vec![unresolved_col("a")])?
+            .build();
+        let expected: LogicalPlan = Project::try_new(
+            new_aggregate,
+            vec![resolved_col("b"), resolved_col("a"), derived],
+        )?
+        .into();
+
+        assert_optimized_plan_eq(plan, expected.arced())?;
+        Ok(())
+    }
+
+    /// Group-by keys that are all independent (no key is a pure function of
+    /// another) are left untouched.
+    #[test]
+    fn test_independent_groupby_keys_are_a_no_op() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+            Field::new("c", DataType::Int64),
+        ]);
+        let agg = vec![unresolved_col("c").mean()];
+        let group_by = vec![unresolved_col("a"), unresolved_col("b")];
+        let plan = dummy_scan_node(scan_op).aggregate(agg, group_by)?.build();
+
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+
+    /// A single group-by key can never be redundant against itself.
+    #[test]
+    fn test_single_groupby_key_is_a_no_op() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]);
+        let agg = vec![unresolved_col("b").mean()];
+        let group_by = vec![unresolved_col("a")];
+        let plan = dummy_scan_node(scan_op).aggregate(agg, group_by)?.build();
+
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,261 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use common_treenode::{Transformed, TreeNode};
+use daft_dsl::{optimization::get_required_columns, Expr, ExprRef, Operator};
+use indexmap::IndexSet;
+
+use super::OptimizerRule;
+use crate::{
+    ops::{Filter, Unpivot},
+    LogicalPlan,
+};
+
+/// Splits the top-level `AND`-conjuncts of a predicate, recursing through
+/// nested `AND`s so e.g. `a AND (b AND c)` yields `[a, b, c]`.
+fn split_conjuncts(expr: &ExprRef) -> Vec<ExprRef> {
+    match expr.as_ref() {
+        Expr::BinaryOp {
+            op: Operator::And,
+            left,
+            right,
+        } => {
+            let mut conjuncts = split_conjuncts(left);
+            conjuncts.extend(split_conjuncts(right));
+            conjuncts
+        }
+        _ => vec![expr.clone()],
+    }
+}
+
+/// Recombines conjuncts with `AND`. Callers only ever pass a non-empty list.
+fn combine_conjuncts(mut conjuncts: Vec<ExprRef>) -> ExprRef {
+    let first = conjuncts.remove(0);
+    conjuncts.into_iter().fold(first, |acc, next| {
+        Expr::BinaryOp {
+            op: Operator::And,
+            left: acc,
+            right: next,
+        }
+        .arced()
+    })
+}
+
+/// Pushes the conjuncts of a `Filter` sitting directly above an `Unpivot`
+/// that reference only the unpivot's passthrough `ids` columns down below
+/// the `Unpivot`, leaving any conjunct that touches the generated
+/// `variable`/`value` columns exactly where it is.
+///
+/// This is the row-expanding-node analogue of `PushDownProjection`: an
+/// `Unpivot` multiplies each input row into one row per melted value column,
+/// so a predicate that could already be evaluated before the fan-out -- and
+/// from there pushed further into `Pushdowns.filters` by the scan-level
+/// filter pushdown -- shouldn't have to run once per generated row instead.
+#[derive(Default, Debug)]
+pub struct PushDownFilterThroughUnpivot {}
+
+impl PushDownFilterThroughUnpivot {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn try_optimize_filter(
+        &self,
+        filter: &Filter,
+        plan: Arc<LogicalPlan>,
+    ) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        let LogicalPlan::Unpivot(unpivot) = filter.input.as_ref() else {
+            return Ok(Transformed::no(plan));
+        };
+
+        let id_names: IndexSet<String> =
+            unpivot.ids.iter().map(|e| e.name().to_string()).collect();
+
+        let conjuncts = split_conjuncts(&filter.predicate);
+        let (pushable, remaining): (Vec<ExprRef>, Vec<ExprRef>) =
+            conjuncts.into_iter().partition(|c| {
+                get_required_columns(c)
+                    .iter()
+                    .all(|col| id_names.contains(col))
+            });
+
+        if pushable.is_empty() {
+            return Ok(Transformed::no(plan));
+        }
+
+        let new_unpivot_input: LogicalPlan =
+            Filter::try_new(unpivot.input.clone(), combine_conjuncts(pushable))?.into();
+        let new_unpivot: LogicalPlan = Unpivot::try_new(
+            new_unpivot_input.into(),
+            unpivot.ids.clone(),
+            unpivot.values.clone(),
+            unpivot.variable_column_name.clone(),
+            unpivot.value_column_name.clone(),
+        )?
+        .into();
+
+        if remaining.is_empty() {
+            Ok(Transformed::yes(new_unpivot.into()))
+        } else {
+            let new_plan: LogicalPlan =
+                Filter::try_new(new_unpivot.into(), combine_conjuncts(remaining))?.into();
+            Ok(Transformed::yes(new_plan.into()))
+        }
+    }
+}
+
+impl OptimizerRule for PushDownFilterThroughUnpivot {
+    fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        plan.transform_down(|node| match node.as_ref() {
+            LogicalPlan::Filter(filter) => self.try_optimize_filter(filter, node.clone()),
+            _ => Ok(Transformed::no(node)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::DaftResult;
+    use daft_core::prelude::*;
+    use daft_dsl::resolved_col;
+
+    use super::PushDownFilterThroughUnpivot;
+    use crate::{
+        ops::{Filter, Unpivot},
+        optimization::{
+            optimizer::{RuleBatch, RuleExecutionStrategy},
+            test::assert_optimized_plan_with_rules_eq,
+        },
+        test::{dummy_scan_node, dummy_scan_operator},
+        LogicalPlan,
+    };
+
+    fn assert_optimized_plan_eq(
+        plan: Arc<LogicalPlan>,
+        expected: Arc<LogicalPlan>,
+    ) -> DaftResult<()> {
+        assert_optimized_plan_with_rules_eq(
+            plan,
+            expected,
+            vec![RuleBatch::new(
+                vec![Box::new(PushDownFilterThroughUnpivot::new())],
+                RuleExecutionStrategy::Once,
+            )],
+        )
+    }
+
+    /// A predicate referencing only a passthrough `ids` column is pushed
+    /// entirely below the `Unpivot`, leaving no `Filter` above it.
+    #[test]
+    fn test_pushes_predicate_on_id_column_below_unpivot() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("Jan", DataType::Int64),
+            Field::new("Feb", DataType::Int64),
+        ]);
+        let unpivot = LogicalPlan::Unpivot(
+            Unpivot::try_new(
+                dummy_scan_node(scan_op.clone()).build(),
+                vec![resolved_col("id")],
+                vec![resolved_col("Jan"), resolved_col("Feb")],
+                "month".to_string(),
+                "inventory".to_string(),
+            )
+            .unwrap(),
+        );
+        let plan: LogicalPlan =
+            Filter::try_new(unpivot.into(), resolved_col("id").eq(daft_dsl::lit(1)))?.into();
+
+        let expected_scan = dummy_scan_node(scan_op.clone()).build();
+        let expected_unpivot = Unpivot::try_new(
+            Filter::try_new(expected_scan, resolved_col("id").eq(daft_dsl::lit(1)))?.into(),
+            vec![resolved_col("id")],
+            vec![resolved_col("Jan"), resolved_col("Feb")],
+            "month".to_string(),
+            "inventory".to_string(),
+        )?;
+        let expected = LogicalPlan::Unpivot(expected_unpivot);
+
+        assert_optimized_plan_eq(plan.arced(), expected.arced())?;
+        Ok(())
+    }
+
+    /// A predicate that touches the generated `value` column is left exactly
+    /// where it is, above the `Unpivot`.
+    #[test]
+    fn test_does_not_push_predicate_on_generated_value_column() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("Jan", DataType::Int64),
+            Field::new("Feb", DataType::Int64),
+        ]);
+        let unpivot = LogicalPlan::Unpivot(
+            Unpivot::try_new(
+                dummy_scan_node(scan_op.clone()).build(),
+                vec![resolved_col("id")],
+                vec![resolved_col("Jan"), resolved_col("Feb")],
+                "month".to_string(),
+                "inventory".to_string(),
+            )
+            .unwrap(),
+        );
+        let plan: LogicalPlan = Filter::try_new(
+            unpivot.into(),
+            resolved_col("inventory").eq(daft_dsl::lit(1)),
+        )?
+        .into();
+        let plan = plan.arced();
+
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+        Ok(())
+    }
+
+    /// A conjunction with one conjunct on an `ids` column and another on the
+    /// generated `value` column is split: the pushable half moves below the
+    /// `Unpivot`, and the other half stays above it.
+    #[test]
+    fn test_splits_mixed_conjuncts_across_unpivot() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("id", DataType::Int64),
+            Field::new("Jan", DataType::Int64),
+            Field::new("Feb", DataType::Int64),
+        ]);
+        let unpivot = LogicalPlan::Unpivot(
+            Unpivot::try_new(
+                dummy_scan_node(scan_op.clone()).build(),
+                vec![resolved_col("id")],
+                vec![resolved_col("Jan"), resolved_col("Feb")],
+                "month".to_string(),
+                "inventory".to_string(),
+            )
+            .unwrap(),
+        );
+        let pred = daft_dsl::Expr::BinaryOp {
+            op: daft_dsl::Operator::And,
+            left: resolved_col("id").eq(daft_dsl::lit(1)),
+            right: resolved_col("inventory").eq(daft_dsl::lit(2)),
+        }
+        .arced();
+        let plan: LogicalPlan = Filter::try_new(unpivot.into(), pred)?.into();
+
+        let expected_scan = dummy_scan_node(scan_op.clone()).build();
+        let expected_unpivot: LogicalPlan = Unpivot::try_new(
+            Filter::try_new(expected_scan, resolved_col("id").eq(daft_dsl::lit(1)))?.into(),
+            vec![resolved_col("id")],
+            vec![resolved_col("Jan"), resolved_col("Feb")],
+            "month".to_string(),
+            "inventory".to_string(),
+        )?
+        .into();
+        let expected: LogicalPlan = Filter::try_new(
+            expected_unpivot.into(),
+            resolved_col("inventory").eq(daft_dsl::lit(2)),
+        )?
+        .into();
+
+        assert_optimized_plan_eq(plan.arced(), expected.arced())?;
+        Ok(())
+    }
+}
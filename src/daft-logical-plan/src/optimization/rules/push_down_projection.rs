@@ -1,9 +1,10 @@
 use std::{collections::HashMap, sync::Arc};
 
 use common_error::DaftResult;
-use common_treenode::{DynTreeNode, Transformed, TreeNode};
+use common_treenode::{DynTreeNode, Transformed, TreeNode, TreeNodeRecursion};
 use daft_core::prelude::*;
 use daft_dsl::{
+    functions::{struct_::StructExpr, FunctionExpr},
     is_udf,
     optimization::{get_required_columns, replace_columns_with_expressions, requires_computation},
     resolved_col, Column, Expr, ExprRef, ResolvedColumn,
@@ -13,11 +14,161 @@ use itertools::Itertools;
 
 use super::OptimizerRule;
 use crate::{
-    ops::{Aggregate, Join, Pivot, Project, Source, UDFProject},
+    ops::{Aggregate, Join, Pivot, Project, Source, UDFProject, Window},
     source_info::SourceInfo,
     LogicalPlan, LogicalPlanRef,
 };
 
+/// If `expr` is one or more nested struct-field-get calls bottoming out at a
+/// bare column reference (e.g. `get_field(get_field(col("a"), "b"), "c")`),
+/// returns `(root_column_name, dotted_path)`, e.g. `("a", "a.b.c")`. Returns
+/// `None` for any other shape, including a bare column reference by itself --
+/// callers treat that as an opaque, whole-column use.
+fn struct_get_path(expr: &ExprRef) -> Option<(String, String)> {
+    let Expr::Function {
+        func: FunctionExpr::Struct(StructExpr::Get(field)),
+        inputs,
+    } = expr.as_ref()
+    else {
+        return None;
+    };
+    let [inner] = inputs.as_slice() else {
+        return None;
+    };
+    match inner.as_ref() {
+        Expr::Column(Column::Resolved(ResolvedColumn::Basic(root))) => {
+            Some((root.to_string(), format!("{root}.{field}")))
+        }
+        _ => {
+            let (root, parent_path) = struct_get_path(inner)?;
+            Some((root, format!("{parent_path}.{field}")))
+        }
+    }
+}
+
+/// Drops `projection` if it's a no-op (selecting exactly all of its child's
+/// columns, in the same order, with no renaming), or merges it with a
+/// directly upstream `Project` when that upstream's computation-required
+/// columns are each referenced only once in `projection`.
+///
+/// Shared between [`PushDownProjection`] (which applies it as the first step
+/// of its own per-node column-pruning pass) and
+/// [`super::eliminate_redundant_projections::EliminateRedundantProjections`]
+/// (which applies it standalone, before column pruning runs at all, so the
+/// plan's `Project` count is already minimal by the time `PushDownProjection`
+/// starts computing required columns).
+pub(crate) fn try_merge_or_drop_project(
+    projection: &Project,
+    plan: Arc<LogicalPlan>,
+) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+    let upstream_plan = &projection.input;
+    let upstream_schema = upstream_plan.schema();
+
+    // First, drop this projection if it is a no-op
+    // (selecting exactly all parent columns in the same order and nothing else).
+    let projection_is_noop = {
+        // Short circuit early if the projection length is different (obviously not a no-op).
+        upstream_schema.names().len() == projection.projection.len()
+            && projection
+                .projection
+                .iter()
+                .zip(upstream_schema.names().iter())
+                .all(|(expr, upstream_col)| match expr.as_ref() {
+                    Expr::Column(Column::Resolved(ResolvedColumn::Basic(colname))) => {
+                        colname.as_ref() == upstream_col
+                    }
+                    _ => false,
+                })
+    };
+    if projection_is_noop {
+        // `upstream_plan` is already fully optimized (it's either the
+        // child the bottom-up traversal just finished with, or was
+        // itself settled via `try_optimize_node_to_fixpoint` before
+        // being spliced in), so dropping down to it needs no further
+        // pass.
+        return Ok(Transformed::yes(upstream_plan.clone()));
+    }
+
+    // Next, check if the upstream is another projection we can merge with.
+    // This is possible iff the upstream projection's computation-required columns
+    // are each only used once in this downstream projection.
+    if let LogicalPlan::Project(upstream_projection) = upstream_plan.as_ref() {
+        // Get all the computation-required columns from the upstream projection.
+        let upstream_computations = upstream_projection
+            .projection
+            .iter()
+            .filter_map(|e| {
+                e.input_mapping().map_or_else(
+                    // None means computation required -> Some(colname)
+                    || Some(e.name().to_string()),
+                    // Some(computation not required) -> None
+                    |_| None,
+                )
+            })
+            .collect::<IndexSet<_>>();
+
+        // For each of them, make sure they are used only once in this downstream projection.
+        let mut exprs_to_walk: Vec<Arc<Expr>> = projection.projection.clone();
+
+        let mut upstream_computations_used = IndexSet::new();
+        let mut okay_to_merge = true;
+
+        while !exprs_to_walk.is_empty() {
+            exprs_to_walk = exprs_to_walk
+                .iter()
+                .flat_map(|expr| {
+                    // If it's a reference for a column that requires computation,
+                    // record it.
+                    if okay_to_merge
+                        && let Expr::Column(Column::Resolved(ResolvedColumn::Basic(name))) =
+                            expr.as_ref()
+                        && upstream_computations.contains(name.as_ref())
+                    {
+                        okay_to_merge =
+                            okay_to_merge && upstream_computations_used.insert(name.to_string());
+                    }
+                    if okay_to_merge {
+                        expr.children()
+                    } else {
+                        // Short circuit to avoid continuing walking the tree.
+                        vec![]
+                    }
+                })
+                .collect();
+        }
+
+        // If the upstream is okay to merge into the current projection,
+        // do the merge.
+        if okay_to_merge {
+            // Get the name and expression for each of the upstream columns.
+            let upstream_names_to_exprs = upstream_projection
+                .projection
+                .iter()
+                .map(|e| (e.name().to_string(), e.clone()))
+                .collect::<HashMap<_, _>>();
+
+            // Merge the projections by applying the upstream expression substitutions
+            // to the current projection.
+            let merged_projection = projection
+                .projection
+                .iter()
+                .map(|e| replace_columns_with_expressions(e.clone(), &upstream_names_to_exprs))
+                .collect();
+
+            // Make a new projection node with the merged projections.
+            // `upstream_projection.input` is already fully optimized;
+            // the caller's fixpoint loop will dispatch on this merged
+            // Project again (it may itself be a no-op, or merge again
+            // with whatever is now directly upstream of it).
+            let new_plan: LogicalPlan =
+                Project::try_new(upstream_projection.input.clone(), merged_projection)?.into();
+            return Ok(Transformed::yes(new_plan.into()));
+        }
+    }
+
+    Ok(Transformed::no(plan))
+}
+
 #[derive(Default, Debug)]
 pub struct PushDownProjection {}
 
@@ -31,112 +182,13 @@ impl PushDownProjection {
         projection: &Project,
         plan: Arc<LogicalPlan>,
     ) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
-        let upstream_plan = &projection.input;
-        let upstream_schema = upstream_plan.schema();
-
-        // First, drop this projection if it is a no-op
-        // (selecting exactly all parent columns in the same order and nothing else).
-        let projection_is_noop = {
-            // Short circuit early if the projection length is different (obviously not a no-op).
-            upstream_schema.names().len() == projection.projection.len()
-                && projection
-                    .projection
-                    .iter()
-                    .zip(upstream_schema.names().iter())
-                    .all(|(expr, upstream_col)| match expr.as_ref() {
-                        Expr::Column(Column::Resolved(ResolvedColumn::Basic(colname))) => {
-                            colname.as_ref() == upstream_col
-                        }
-                        _ => false,
-                    })
-        };
-        if projection_is_noop {
-            // Projection discarded but new root node has not been looked at;
-            // look at the new root node.
-            let new_plan = self
-                .try_optimize_node(upstream_plan.clone())?
-                .or(Transformed::yes(upstream_plan.clone()));
-            return Ok(new_plan);
+        let merged_or_dropped = try_merge_or_drop_project(projection, plan.clone())?;
+        if merged_or_dropped.transformed {
+            return Ok(merged_or_dropped);
         }
 
-        // Next, check if the upstream is another projection we can merge with.
-        // This is possible iff the upstream projection's computation-required columns
-        // are each only used once in this downstream projection.
-        if let LogicalPlan::Project(upstream_projection) = upstream_plan.as_ref() {
-            // Get all the computation-required columns from the upstream projection.
-            let upstream_computations = upstream_projection
-                .projection
-                .iter()
-                .filter_map(|e| {
-                    e.input_mapping().map_or_else(
-                        // None means computation required -> Some(colname)
-                        || Some(e.name().to_string()),
-                        // Some(computation not required) -> None
-                        |_| None,
-                    )
-                })
-                .collect::<IndexSet<_>>();
-
-            // For each of them, make sure they are used only once in this downstream projection.
-            let mut exprs_to_walk: Vec<Arc<Expr>> = projection.projection.clone();
-
-            let mut upstream_computations_used = IndexSet::new();
-            let mut okay_to_merge = true;
-
-            while !exprs_to_walk.is_empty() {
-                exprs_to_walk = exprs_to_walk
-                    .iter()
-                    .flat_map(|expr| {
-                        // If it's a reference for a column that requires computation,
-                        // record it.
-                        if okay_to_merge
-                            && let Expr::Column(Column::Resolved(ResolvedColumn::Basic(name))) =
-                                expr.as_ref()
-                            && upstream_computations.contains(name.as_ref())
-                        {
-                            okay_to_merge = okay_to_merge
-                                && upstream_computations_used.insert(name.to_string());
-                        }
-                        if okay_to_merge {
-                            expr.children()
-                        } else {
-                            // Short circuit to avoid continuing walking the tree.
-                            vec![]
-                        }
-                    })
-                    .collect();
-            }
-
-            // If the upstream is okay to merge into the current projection,
-            // do the merge.
-            if okay_to_merge {
-                // Get the name and expression for each of the upstream columns.
-                let upstream_names_to_exprs = upstream_projection
-                    .projection
-                    .iter()
-                    .map(|e| (e.name().to_string(), e.clone()))
-                    .collect::<HashMap<_, _>>();
-
-                // Merge the projections by applying the upstream expression substitutions
-                // to the current projection.
-                let merged_projection = projection
-                    .projection
-                    .iter()
-                    .map(|e| replace_columns_with_expressions(e.clone(), &upstream_names_to_exprs))
-                    .collect();
-
-                // Make a new projection node with the merged projections.
-                let new_plan: LogicalPlan =
-                    Project::try_new(upstream_projection.input.clone(), merged_projection)?.into();
-                let new_plan: Arc<LogicalPlan> = new_plan.into();
-
-                // Root node is changed, look at it again.
-                let new_plan = self
-                    .try_optimize_node(new_plan.clone())?
-                    .or(Transformed::yes(new_plan));
-                return Ok(new_plan);
-            }
-        }
+        let upstream_plan = &projection.input;
+        let upstream_schema = upstream_plan.schema();
 
         match upstream_plan.as_ref() {
             LogicalPlan::Source(source) => {
@@ -146,28 +198,74 @@ impl PushDownProjection {
                 };
                 match source.source_info.as_ref() {
                     SourceInfo::Physical(external_info) => {
-                        if required_columns.len() < upstream_schema.names().len() {
+                        // A struct column read only through field access can be
+                        // narrowed even when every top-level column is needed.
+                        let narrows_struct = external_info.pushdowns.columns.is_none()
+                            && projection
+                                .projection
+                                .iter()
+                                .any(|e| e.exists(|n| struct_get_path(n).is_some()));
+                        if required_columns.len() < upstream_schema.names().len() || narrows_struct
+                        {
                             let pruned_upstream_schema = upstream_schema
                                 .into_iter()
                                 .filter(|field| required_columns.contains(&field.name))
                                 .cloned()
                                 .collect::<Vec<_>>();
                             let schema = Schema::new(pruned_upstream_schema);
+
+                            // A struct column referenced *only* through
+                            // `get_field` chains (never opaquely, e.g.
+                            // passed whole to a UDF or aliased as-is) can
+                            // have its physical pushdown narrowed to just
+                            // the specific subfield paths actually used,
+                            // instead of the whole struct, so the scan
+                            // never materializes unused subfields. The
+                            // logical schema above keeps the struct column
+                            // as-is; only the pushdown column list changes.
+                            let mut opaque_columns: IndexSet<String> = IndexSet::new();
+                            let mut struct_paths: HashMap<String, IndexSet<String>> =
+                                HashMap::new();
+                            for expr in &projection.projection {
+                                expr.apply(|node| {
+                                    if let Some((root, path)) = struct_get_path(node) {
+                                        struct_paths.entry(root).or_default().insert(path);
+                                        // The children of this chain are
+                                        // part of the same struct-get use,
+                                        // not a separate opaque reference.
+                                        return Ok(TreeNodeRecursion::Jump);
+                                    }
+                                    if let Expr::Column(Column::Resolved(
+                                        ResolvedColumn::Basic(name),
+                                    )) = node.as_ref()
+                                    {
+                                        opaque_columns.insert(name.to_string());
+                                    }
+                                    Ok(TreeNodeRecursion::Continue)
+                                })?;
+                            }
+
+                            let pushdown_columns: Vec<String> = required_columns
+                                .iter()
+                                .flat_map(|name| match struct_paths.get(name) {
+                                    Some(paths) if !opaque_columns.contains(name) => {
+                                        paths.iter().cloned().collect()
+                                    }
+                                    _ => vec![name.clone()],
+                                })
+                                .collect();
+
                             let new_source: LogicalPlan = Source::new(
                                 schema.into(),
                                 Arc::new(SourceInfo::Physical(external_info.with_pushdowns(
-                                    external_info.pushdowns.with_columns(Some(Arc::new(
-                                        required_columns.iter().cloned().collect(),
-                                    ))),
+                                    external_info
+                                        .pushdowns
+                                        .with_columns(Some(Arc::new(pushdown_columns))),
                                 ))),
                             )
                             .into();
                             let new_plan = Arc::new(plan.with_new_children(&[new_source.into()]));
-                            // Retry optimization now that the upstream node is different.
-                            let new_plan = self
-                                .try_optimize_node(new_plan.clone())?
-                                .or(Transformed::yes(new_plan));
-                            Ok(new_plan)
+                            Ok(Transformed::yes(new_plan))
                         } else {
                             Ok(Transformed::no(plan))
                         }
@@ -181,7 +279,14 @@ impl PushDownProjection {
             LogicalPlan::Project(upstream_projection) => {
                 // Prune columns from the child projection that are not used in this projection.
                 let required_columns = &plan.required_columns()[0];
-                if required_columns.len() < upstream_schema.names().len() {
+                if required_columns.is_empty() {
+                    // This projection uses none of the upstream projection's
+                    // outputs: drop the upstream projection entirely rather than
+                    // leave a degenerate, zero-column node behind.
+                    let new_upstream = upstream_projection.input.clone();
+                    let new_plan = Arc::new(plan.with_new_children(&[new_upstream]));
+                    Ok(Transformed::yes(new_plan))
+                } else if required_columns.len() < upstream_schema.names().len() {
                     let pruned_upstream_projections = upstream_projection
                         .projection
                         .iter()
@@ -196,11 +301,7 @@ impl PushDownProjection {
                     .into();
 
                     let new_plan = Arc::new(plan.with_new_children(&[new_upstream.into()]));
-                    // Retry optimization now that the upstream node is different.
-                    let new_plan = self
-                        .try_optimize_node(new_plan.clone())?
-                        .or(Transformed::yes(new_plan));
-                    Ok(new_plan)
+                    Ok(Transformed::yes(new_plan))
                 } else {
                     Ok(Transformed::no(plan))
                 }
@@ -215,20 +316,58 @@ impl PushDownProjection {
                     .cloned()
                     .collect::<Vec<_>>();
 
-                if pruned_aggregate_exprs.len() < aggregate.aggregations.len() {
+                if pruned_aggregate_exprs.is_empty() && !aggregate.aggregations.is_empty() {
+                    let groupby_still_needed = aggregate
+                        .groupby
+                        .iter()
+                        .any(|e| required_columns.contains(e.name()));
+
+                    let new_upstream: Arc<LogicalPlan> = if groupby_still_needed {
+                        // None of the aggregations survive, but some group-by
+                        // key is still required: this is equivalent to a
+                        // distinct over the group-by keys, so keep the
+                        // Aggregate but drop every aggregation expression.
+                        let new_aggregate: LogicalPlan = Aggregate::try_new(
+                            aggregate.input.clone(),
+                            vec![],
+                            aggregate.groupby.clone(),
+                        )?
+                        .into();
+                        // Dropping every aggregation can only shrink the
+                        // Aggregate's own required input columns (down to
+                        // just the group-by keys); `new_aggregate` is
+                        // spliced in as the direct child of the returned
+                        // `Project`, a position `try_optimize_aggregation`
+                        // is never dispatched to on its own, so settle it
+                        // locally now.
+                        self.try_optimize_node_to_fixpoint(new_aggregate.into())?
+                            .data
+                    } else {
+                        // Neither the aggregations nor the group-by keys are
+                        // used downstream: the whole Aggregate is dead and
+                        // collapses to its input.
+                        aggregate.input.clone()
+                    };
+
+                    let new_plan = Arc::new(plan.with_new_children(&[new_upstream]));
+                    Ok(Transformed::yes(new_plan))
+                } else if pruned_aggregate_exprs.len() < aggregate.aggregations.len() {
                     let new_upstream: LogicalPlan = Aggregate::try_new(
                         aggregate.input.clone(),
                         pruned_aggregate_exprs,
                         aggregate.groupby.clone(),
                     )?
                     .into();
+                    // Same reasoning as above: the surviving aggregations
+                    // may no longer need every input column the original
+                    // (unpruned) Aggregate did, so settle the new node to
+                    // recompute its own input pruning before splicing it in.
+                    let new_upstream = self
+                        .try_optimize_node_to_fixpoint(new_upstream.into())?
+                        .data;
 
-                    let new_plan = Arc::new(plan.with_new_children(&[new_upstream.into()]));
-                    // Retry optimization now that the upstream node is different.
-                    let new_plan = self
-                        .try_optimize_node(new_plan.clone())?
-                        .or(Transformed::yes(new_plan));
-                    Ok(new_plan)
+                    let new_plan = Arc::new(plan.with_new_children(&[new_upstream]));
+                    Ok(Transformed::yes(new_plan))
                 } else {
                     Ok(Transformed::no(plan))
                 }
@@ -237,18 +376,13 @@ impl PushDownProjection {
                 let required_columns = &plan.required_columns()[0];
                 if !required_columns.contains(upstream_actor_pool_projection.project.name()) {
                     // We don't need the UDFProject, just convert to a regular project
-                    let new_plan = LogicalPlan::Project(Project::try_new(
+                    let new_upstream = LogicalPlan::Project(Project::try_new(
                         upstream_actor_pool_projection.input.clone(),
                         upstream_actor_pool_projection.passthrough_columns.clone(),
                     )?)
                     .arced();
-                    let new_plan = Arc::new(plan.with_new_children(&[new_plan.into()]));
-
-                    // Retry optimization now that the upstream node is different.
-                    let new_plan = self
-                        .try_optimize_node(new_plan.clone())?
-                        .or(Transformed::yes(new_plan));
-                    return Ok(new_plan);
+                    let new_plan = Arc::new(plan.with_new_children(&[new_upstream]));
+                    return Ok(Transformed::yes(new_plan));
                 }
 
                 // Attempt to merge the current Projection into the upstream UDFProject
@@ -318,11 +452,7 @@ impl PushDownProjection {
                             .arced()
                         };
 
-                        // Retry optimization now that the node is different.
-                        let new_plan = self
-                            .try_optimize_node(new_plan.clone())?
-                            .or(Transformed::yes(new_plan));
-                        return Ok(new_plan);
+                        return Ok(Transformed::yes(new_plan));
                     }
                 }
 
@@ -341,12 +471,7 @@ impl PushDownProjection {
                     )?)
                     .arced();
                     let new_plan = Arc::new(plan.with_new_children(&[new_upstream]));
-
-                    // Retry optimization now that the upstream node is different.
-                    let new_plan = self
-                        .try_optimize_node(new_plan.clone())?
-                        .or(Transformed::yes(new_plan));
-                    Ok(new_plan)
+                    Ok(Transformed::yes(new_plan))
                 } else {
                     Ok(Transformed::no(plan))
                 }
@@ -384,14 +509,16 @@ impl PushDownProjection {
 
                     Project::try_new(grand_upstream_plan.clone(), pushdown_column_exprs)?.into()
                 };
-
-                let new_upstream = upstream_plan.with_new_children(&[new_subprojection.into()]);
+                // `new_subprojection` is spliced in two levels below `plan`,
+                // underneath `upstream_plan`, so the bottom-up traversal
+                // will never visit it on its own: settle it locally now.
+                let new_subprojection = self
+                    .try_optimize_node_to_fixpoint(new_subprojection.into())?
+                    .data;
+
+                let new_upstream = upstream_plan.with_new_children(&[new_subprojection]);
                 let new_plan = Arc::new(plan.with_new_children(&[new_upstream.into()]));
-                // Retry optimization now that the upstream node is different.
-                let new_plan = self
-                    .try_optimize_node(new_plan.clone())?
-                    .or(Transformed::yes(new_plan));
-                Ok(new_plan)
+                Ok(Transformed::yes(new_plan))
             }
             LogicalPlan::Unpivot(unpivot) => {
                 let combined_dependencies = plan
@@ -422,13 +549,12 @@ impl PushDownProjection {
 
                 let new_subprojection: LogicalPlan =
                     Project::try_new(grand_upstream_plan.clone(), can_be_pushed_down)?.into();
-                let new_upstream = upstream_plan.with_new_children(&[new_subprojection.into()]);
+                let new_subprojection = self
+                    .try_optimize_node_to_fixpoint(new_subprojection.into())?
+                    .data;
+                let new_upstream = upstream_plan.with_new_children(&[new_subprojection]);
                 let new_plan = Arc::new(plan.with_new_children(&[new_upstream.into()]));
-                // Retry optimization now that the upstream node is different.
-                let new_plan = self
-                    .try_optimize_node(new_plan.clone())?
-                    .or(Transformed::yes(new_plan));
-                Ok(new_plan)
+                Ok(Transformed::yes(new_plan))
             }
             LogicalPlan::Concat(concat) => {
                 // Get required columns from projection and upstream.
@@ -454,20 +580,25 @@ impl PushDownProjection {
                 let new_left_subprojection: LogicalPlan = {
                     Project::try_new(concat.input.clone(), pushdown_column_exprs.clone())?.into()
                 };
+                let new_left_subprojection = self
+                    .try_optimize_node_to_fixpoint(new_left_subprojection.into())?
+                    .data;
                 let new_right_subprojection: LogicalPlan =
                     { Project::try_new(concat.other.clone(), pushdown_column_exprs)?.into() };
+                let new_right_subprojection = self
+                    .try_optimize_node_to_fixpoint(new_right_subprojection.into())?
+                    .data;
 
-                let new_upstream = upstream_plan.with_new_children(&[
-                    new_left_subprojection.into(),
-                    new_right_subprojection.into(),
-                ]);
+                let new_upstream = upstream_plan
+                    .with_new_children(&[new_left_subprojection, new_right_subprojection]);
                 let new_plan = Arc::new(plan.with_new_children(&[new_upstream.into()]));
-                // Retry optimization now that the upstream node is different.
-                let new_plan = self
-                    .try_optimize_node(new_plan.clone())?
-                    .or(Transformed::yes(new_plan));
-                Ok(new_plan)
+                Ok(Transformed::yes(new_plan))
             }
+            // A `Union` is always rewritten into a `Concat` (wrapped in a
+            // `Distinct` for the non-`all` case) before this rule runs, so
+            // the `Concat` arm above is what actually pushes a pruned,
+            // position-matched projection into both sides of what was
+            // originally a `Union`.
             LogicalPlan::Union(_) => unreachable!("Union should have been optimized away"),
             LogicalPlan::Join(join) => {
                 // Get required columns from projection and both upstreams.
@@ -479,38 +610,45 @@ impl PushDownProjection {
                     panic!()
                 };
 
-                /// For one side of the join, see if a non-vacuous pushdown is possible.
-                fn maybe_project_upstream_input(
-                    side: &LogicalPlanRef,
-                    side_dependencies: &IndexSet<String>,
-                    projection_dependencies: &IndexSet<String>,
-                ) -> DaftResult<Transformed<LogicalPlanRef>> {
-                    let schema = side.schema();
-                    let upstream_names: IndexSet<String> =
-                        schema.field_names().map(ToString::to_string).collect();
-
-                    let combined_dependencies: IndexSet<_> = side_dependencies
-                        .union(
-                            &upstream_names
-                                .intersection(projection_dependencies)
-                                .cloned()
-                                .collect::<IndexSet<_>>(),
-                        )
-                        .cloned()
-                        .collect();
-
-                    if combined_dependencies.len() < upstream_names.len() {
-                        let pushdown_column_exprs: Vec<ExprRef> = combined_dependencies
-                            .into_iter()
-                            .map(resolved_col)
+                // For one side of the join, see if a non-vacuous pushdown is
+                // possible. The resulting `Project`, if any, is spliced in
+                // two levels below `plan` (underneath the new `Join`), so it
+                // needs to be settled locally here rather than relying on
+                // the bottom-up traversal to revisit it.
+                let maybe_project_upstream_input =
+                    |side: &LogicalPlanRef,
+                     side_dependencies: &IndexSet<String>,
+                     projection_dependencies: &IndexSet<String>|
+                     -> DaftResult<Transformed<LogicalPlanRef>> {
+                        let schema = side.schema();
+                        let upstream_names: IndexSet<String> =
+                            schema.field_names().map(ToString::to_string).collect();
+
+                        let combined_dependencies: IndexSet<_> = side_dependencies
+                            .union(
+                                &upstream_names
+                                    .intersection(projection_dependencies)
+                                    .cloned()
+                                    .collect::<IndexSet<_>>(),
+                            )
+                            .cloned()
                             .collect();
-                        let new_project: LogicalPlan =
-                            Project::try_new(side.clone(), pushdown_column_exprs)?.into();
-                        Ok(Transformed::yes(new_project.into()))
-                    } else {
-                        Ok(Transformed::no(side.clone()))
-                    }
-                }
+
+                        if combined_dependencies.len() < upstream_names.len() {
+                            let pushdown_column_exprs: Vec<ExprRef> = combined_dependencies
+                                .into_iter()
+                                .map(resolved_col)
+                                .collect();
+                            let new_project: LogicalPlan =
+                                Project::try_new(side.clone(), pushdown_column_exprs)?.into();
+                            let new_project = self
+                                .try_optimize_node_to_fixpoint(new_project.into())?
+                                .data;
+                            Ok(Transformed::yes(new_project))
+                        } else {
+                            Ok(Transformed::no(side.clone()))
+                        }
+                    };
 
                 let new_left_upstream = maybe_project_upstream_input(
                     &join.left,
@@ -531,13 +669,7 @@ impl PushDownProjection {
                         .with_new_children(&[new_left_upstream.data, new_right_upstream.data]);
 
                     let new_plan = Arc::new(plan.with_new_children(&[new_join.into()]));
-
-                    // Retry optimization now that the upstream node is different.
-                    let new_plan = self
-                        .try_optimize_node(new_plan.clone())?
-                        .or(Transformed::yes(new_plan));
-
-                    Ok(new_plan)
+                    Ok(Transformed::yes(new_plan))
                 }
             }
             LogicalPlan::Distinct(distinct) => {
@@ -548,37 +680,51 @@ impl PushDownProjection {
 
                 let plan_req_cols = &plan.required_columns()[0];
                 let distinct_req_cols = &upstream_plan.required_columns()[0];
+                let combined_dependencies: IndexSet<String> =
+                    plan_req_cols.union(distinct_req_cols).cloned().collect();
+
+                // Skip optimization if no columns would be pruned.
+                let grand_upstream_columns = distinct.input.schema().names();
+                if grand_upstream_columns.len() == combined_dependencies.len() {
+                    return Ok(Transformed::no(plan));
+                }
 
                 // Add a new projection underneath the distinct to pass through columns
                 // used by the distinct & current projection node
-                let new_extra_projection = LogicalPlan::Project(Project::try_new(
+                let new_extra_projection: LogicalPlan = Project::try_new(
                     distinct.input.clone(),
-                    plan_req_cols
-                        .union(distinct_req_cols)
+                    combined_dependencies
+                        .into_iter()
                         .map(|e| resolved_col(e.as_str()))
                         .collect::<Vec<_>>(),
-                )?)
-                .arced();
+                )?
+                .into();
+                let new_extra_projection = self
+                    .try_optimize_node_to_fixpoint(new_extra_projection.into())?
+                    .data;
 
                 let new_distinct = upstream_plan
-                    .with_new_children(&[new_extra_projection.into()])
+                    .with_new_children(&[new_extra_projection])
                     .arced();
                 let new_plan = plan.with_new_children(&[new_distinct]).arced();
                 Ok(Transformed::yes(new_plan.into()))
             }
             LogicalPlan::Intersect(_) => {
-                // Cannot push down past an Intersect,
-                // since Intersect implicitly requires all parent columns.
+                // Cannot push a column-subsetting projection down past an
+                // Intersect, since every column on both sides is load-bearing
+                // for row-matching. A projection that only reorders or
+                // renames columns (no column dropped) is left exactly where
+                // it is instead of being pushed down: it still falls through
+                // to `Ok(Transformed::no(plan))` here, which keeps it intact
+                // immediately above the Intersect rather than merging or
+                // discarding it.
                 Ok(Transformed::no(plan))
             }
             LogicalPlan::Pivot(_) | LogicalPlan::MonotonicallyIncreasingId(_) => {
                 // Cannot push down past a Pivot/MonotonicallyIncreasingId because it changes the schema.
                 Ok(Transformed::no(plan))
             }
-            LogicalPlan::Window(_) => {
-                // Cannot push down past a Window because it changes the window calculation results
-                Ok(Transformed::no(plan))
-            }
+            LogicalPlan::Window(window) => self.try_optimize_window(window, plan.clone()),
             LogicalPlan::Sink(_) => {
                 panic!("Bad projection due to upstream sink node: {:?}", projection)
             }
@@ -606,8 +752,15 @@ impl PushDownProjection {
 
                 Project::try_new(upstream_plan.clone(), pushdown_column_exprs)?.into()
             };
-
-            let new_udf_project = plan.with_new_children(&[new_subprojection.into()]);
+            // `new_subprojection` is a brand-new node directly underneath
+            // this UDFProject: the bottom-up traversal already passed this
+            // position by, so settle it locally (e.g. it may merge further
+            // with whatever is upstream of it).
+            let new_subprojection = self
+                .try_optimize_node_to_fixpoint(new_subprojection.into())?
+                .data;
+
+            let new_udf_project = plan.with_new_children(&[new_subprojection]);
             Ok(Transformed::yes(new_udf_project.into()))
         } else {
             Ok(Transformed::no(plan))
@@ -634,8 +787,11 @@ impl PushDownProjection {
 
                 Project::try_new(upstream_plan.clone(), pushdown_column_exprs)?.into()
             };
+            let new_subprojection = self
+                .try_optimize_node_to_fixpoint(new_subprojection.into())?
+                .data;
 
-            let new_aggregation = plan.with_new_children(&[new_subprojection.into()]);
+            let new_aggregation = plan.with_new_children(&[new_subprojection]);
             Ok(Transformed::yes(new_aggregation.into()))
         } else {
             Ok(Transformed::no(plan))
@@ -667,14 +823,15 @@ impl PushDownProjection {
 
                     Project::try_new(join.right.clone(), pushdown_column_exprs)?.into()
                 };
+                let new_subprojection = self
+                    .try_optimize_node_to_fixpoint(new_subprojection.into())?
+                    .data;
 
                 let new_join = plan
-                    .with_new_children(&[(join.left).clone(), new_subprojection.into()])
+                    .with_new_children(&[(join.left).clone(), new_subprojection])
                     .arced();
 
-                Ok(self
-                    .try_optimize_node(new_join.clone())?
-                    .or(Transformed::yes(new_join)))
+                Ok(Transformed::yes(new_join))
             } else {
                 Ok(Transformed::no(plan))
             }
@@ -703,14 +860,93 @@ impl PushDownProjection {
 
                 Project::try_new(upstream_plan.clone(), pushdown_column_exprs)?.into()
             };
+            let new_subprojection = self
+                .try_optimize_node_to_fixpoint(new_subprojection.into())?
+                .data;
 
-            let new_pivot = plan.with_new_children(&[new_subprojection.into()]);
+            let new_pivot = plan.with_new_children(&[new_subprojection]);
             Ok(Transformed::yes(new_pivot.into()))
         } else {
             Ok(Transformed::no(plan))
         }
     }
 
+    fn try_optimize_window(
+        &self,
+        window: &Window,
+        plan: Arc<LogicalPlan>,
+    ) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        // Drop any window function whose output alias the parent projection
+        // never references.
+        let required_columns = &plan.required_columns()[0];
+        let surviving_window_functions: Vec<ExprRef> = window
+            .window_functions
+            .iter()
+            .filter(|e| required_columns.contains(e.name()))
+            .cloned()
+            .collect();
+
+        if surviving_window_functions.len() == window.window_functions.len() {
+            return Ok(Transformed::no(plan));
+        }
+
+        if surviving_window_functions.is_empty() {
+            // None of this Window's own outputs are used: it contributes
+            // nothing beyond passing its input through, so drop it
+            // entirely.
+            let new_plan = Arc::new(plan.with_new_children(&[window.input.clone()]));
+            return Ok(Transformed::yes(new_plan));
+        }
+
+        // Some window functions survive: recompute the Window's required
+        // input columns from just what's left -- its partition-by/order-by
+        // keys, the surviving window functions' own input columns, and
+        // whatever passthrough input columns the parent still needs -- and
+        // push a pruning projection underneath it, mirroring
+        // `try_optimize_aggregation`.
+        let mut input_required: IndexSet<String> = window
+            .window_spec
+            .partition_by
+            .iter()
+            .chain(window.window_spec.order_by.iter())
+            .chain(surviving_window_functions.iter())
+            .flat_map(get_required_columns)
+            .collect();
+        let window_function_names: IndexSet<&str> =
+            window.window_functions.iter().map(|e| e.name()).collect();
+        input_required.extend(
+            required_columns
+                .iter()
+                .filter(|c| !window_function_names.contains(c.as_str()))
+                .cloned(),
+        );
+
+        let new_window_input = if input_required.len() < window.input.schema().names().len() {
+            let pushdown_column_exprs = input_required
+                .iter()
+                .map(|s| resolved_col(s.as_str()))
+                .collect::<Vec<_>>();
+            let new_subprojection: LogicalPlan =
+                Project::try_new(window.input.clone(), pushdown_column_exprs)?.into();
+            // `new_subprojection` is spliced in two levels below `plan`,
+            // underneath the new Window node, so the bottom-up traversal
+            // will never visit it on its own: settle it locally now.
+            self.try_optimize_node_to_fixpoint(new_subprojection.into())?
+                .data
+        } else {
+            window.input.clone()
+        };
+
+        let new_window: LogicalPlan = Window::try_new(
+            new_window_input,
+            surviving_window_functions,
+            window.window_spec.clone(),
+        )?
+        .into();
+        let new_plan = Arc::new(plan.with_new_children(&[new_window.into()]));
+        Ok(Transformed::yes(new_plan))
+    }
+
     fn try_optimize_node(
         &self,
         plan: Arc<LogicalPlan>,
@@ -732,12 +968,105 @@ impl PushDownProjection {
             _ => Ok(Transformed::no(plan)),
         }
     }
+
+    /// Repeatedly applies [`Self::try_optimize_node`] to a single tree
+    /// position (never its children) until it stops reporting a change.
+    ///
+    /// Used both at each node visited by [`Self::try_optimize_worklist`] --
+    /// which already guarantees every child is fully optimized before its
+    /// parent is visited, so no recursion into children is needed here --
+    /// and to settle nodes synthesized partway through a local rewrite
+    /// (e.g. a pushdown `Project` spliced in underneath an unchanged
+    /// `Filter`/`Join`/etc.), which the one-pass bottom-up traversal would
+    /// otherwise never visit on its own.
+    fn try_optimize_node_to_fixpoint(
+        &self,
+        plan: Arc<LogicalPlan>,
+    ) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        let mut current = plan;
+        let mut any_transformed = false;
+        loop {
+            let next = self.try_optimize_node(current)?;
+            current = next.data;
+            if !next.transformed {
+                break;
+            }
+            any_transformed = true;
+        }
+        Ok(if any_transformed {
+            Transformed::yes(current)
+        } else {
+            Transformed::no(current)
+        })
+    }
+
+    /// Iterative, stack-safe equivalent of
+    /// `plan.transform_up(|node| self.try_optimize_node_to_fixpoint(node))`.
+    ///
+    /// `TreeNode::transform_up` recurses through the call stack one frame
+    /// per tree level, so a plan nested deep enough (e.g. a long chain of
+    /// joins or projections assembled programmatically) can blow it. This
+    /// drives the same post-order (bottom-up) traversal from an explicit
+    /// heap-allocated worklist instead: each node is pushed once to find
+    /// its children, then popped again once every child's result is ready,
+    /// at which point it's rebuilt only if at least one child actually
+    /// changed (an unchanged node is reused as-is) and then optimized with
+    /// [`Self::try_optimize_node_to_fixpoint`].
+    fn try_optimize_worklist(
+        &self,
+        root: Arc<LogicalPlan>,
+    ) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
+        enum Frame {
+            Enter(Arc<LogicalPlan>),
+            Exit(Arc<LogicalPlan>, usize),
+        }
+
+        let mut worklist = vec![Frame::Enter(root)];
+        let mut results: Vec<Transformed<Arc<LogicalPlan>>> = Vec::new();
+
+        while let Some(frame) = worklist.pop() {
+            match frame {
+                Frame::Enter(node) => {
+                    let children = node.arc_children();
+                    worklist.push(Frame::Exit(node, children.len()));
+                    worklist.extend(children.into_iter().map(Frame::Enter));
+                }
+                Frame::Exit(node, num_children) => {
+                    let mut any_child_changed = false;
+                    let mut new_children = Vec::with_capacity(num_children);
+                    for _ in 0..num_children {
+                        let child = results
+                            .pop()
+                            .expect("every pushed child has a result by the time its parent exits");
+                        any_child_changed |= child.transformed;
+                        new_children.push(child.data);
+                    }
+
+                    let rebuilt = if any_child_changed {
+                        Arc::new(node.with_new_children(&new_children))
+                    } else {
+                        node
+                    };
+
+                    let optimized = self.try_optimize_node_to_fixpoint(rebuilt)?;
+                    results.push(Transformed::new(
+                        optimized.data,
+                        any_child_changed || optimized.transformed,
+                        optimized.tnr,
+                    ));
+                }
+            }
+        }
+
+        Ok(results
+            .pop()
+            .expect("the worklist always produces exactly one result for the root"))
+    }
 }
 
 impl OptimizerRule for PushDownProjection {
     fn try_optimize(&self, plan: Arc<LogicalPlan>) -> DaftResult<Transformed<Arc<LogicalPlan>>> {
-        let out = plan.transform_down(|node| self.try_optimize_node(node))?;
-        Ok(out)
+        self.try_optimize_worklist(plan)
     }
 }
 
@@ -758,7 +1087,7 @@ mod tests {
     };
 
     use crate::{
-        ops::{Project, Unpivot},
+        ops::{Concat, Project, Unpivot, Window, WindowSpec},
         optimization::{
             optimizer::{RuleBatch, RuleExecutionStrategy},
             rules::PushDownProjection,
@@ -916,6 +1245,80 @@ mod tests {
         Ok(())
     }
 
+    fn struct_get(input: ExprRef, field: &str) -> ExprRef {
+        use daft_dsl::functions::struct_::StructExpr;
+        Expr::Function {
+            func: FunctionExpr::Struct(StructExpr::Get(field.to_string())),
+            inputs: vec![input],
+        }
+        .arced()
+    }
+
+    /// Projection<-Source: a struct column referenced only through
+    /// `get_field` chains gets its physical pushdown narrowed to just the
+    /// specific subfield paths used, instead of the whole struct.
+    #[test]
+    fn test_projection_source_struct_field_pushdown() -> DaftResult<()> {
+        let struct_dtype = DataType::Struct(vec![
+            Field::new("b", DataType::Int64),
+            Field::new("c", DataType::Int64),
+        ]);
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", struct_dtype),
+            Field::new("d", DataType::Int64),
+        ]);
+        let proj = vec![
+            struct_get(unresolved_col("a"), "b").alias("a_b"),
+            unresolved_col("d"),
+        ];
+        let plan = dummy_scan_node(scan_op.clone())
+            .select(proj.clone())?
+            .build();
+
+        let proj_pushdown = vec!["a.b".to_string(), "d".to_string()];
+        let expected = dummy_scan_node_with_pushdowns(
+            scan_op,
+            Pushdowns::default().with_columns(Some(Arc::new(proj_pushdown))),
+        )
+        .select(proj)?
+        .build();
+
+        assert_optimized_plan_eq(plan, expected)?;
+
+        Ok(())
+    }
+
+    /// Projection<-Source: a struct column that's referenced both through
+    /// `get_field` and opaquely (passed whole to something else) keeps its
+    /// whole-column pushdown rather than narrowing to a subfield path.
+    #[test]
+    fn test_projection_source_struct_field_opaque_use_not_narrowed() -> DaftResult<()> {
+        let struct_dtype = DataType::Struct(vec![Field::new("b", DataType::Int64)]);
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", struct_dtype),
+            Field::new("d", DataType::Int64),
+        ]);
+        let proj = vec![
+            struct_get(unresolved_col("a"), "b").alias("a_b"),
+            unresolved_col("a").alias("a_whole"),
+        ];
+        let plan = dummy_scan_node(scan_op.clone())
+            .select(proj.clone())?
+            .build();
+
+        let proj_pushdown = vec!["a".to_string()];
+        let expected = dummy_scan_node_with_pushdowns(
+            scan_op,
+            Pushdowns::default().with_columns(Some(Arc::new(proj_pushdown))),
+        )
+        .select(proj)?
+        .build();
+
+        assert_optimized_plan_eq(plan, expected)?;
+
+        Ok(())
+    }
+
     /// Projection<-Projection column pruning
     #[test]
     fn test_projection_projection() -> DaftResult<()> {
@@ -980,6 +1383,40 @@ mod tests {
         Ok(())
     }
 
+    /// Projection<-Aggregation column pruning: when every aggregation output
+    /// is dropped but a group-by key survives, the Aggregate collapses to a
+    /// group-only node, and the column pruning this newly collapsed node
+    /// itself requires from its input must be recomputed -- down to just the
+    /// surviving group-by key -- rather than keep reading every column the
+    /// original (unpruned) Aggregate needed.
+    #[test]
+    fn test_projection_aggregation_collapses_to_groupby() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+            Field::new("c", DataType::Int64),
+        ]);
+        let agg = vec![unresolved_col("a").mean(), unresolved_col("b").mean()];
+        let group_by = vec![unresolved_col("c")];
+        let proj = vec![unresolved_col("c")];
+        let plan = dummy_scan_node(scan_op.clone())
+            .aggregate(agg, group_by.clone())?
+            .select(proj.clone())?
+            .build();
+
+        let proj_pushdown = vec!["c".to_string()];
+        let expected = dummy_scan_node_with_pushdowns(
+            scan_op,
+            Pushdowns::default().with_columns(Some(Arc::new(proj_pushdown))),
+        )
+        .aggregate(vec![], group_by)?
+        .build();
+
+        assert_optimized_plan_eq(plan, expected)?;
+
+        Ok(())
+    }
+
     /// Projection<-X pushes down the combined required columns
     #[test]
     fn test_projection_pushdown() -> DaftResult<()> {
@@ -1235,4 +1672,153 @@ mod tests {
         .into();
         assert_optimized_plan_eq(plan, expected).unwrap();
     }
+
+    /// Projection<-Window: a window function whose output alias the parent
+    /// never references is dropped, and the Window's own required input
+    /// columns are recomputed down to just its partition-by keys and the
+    /// surviving window function's input, pruning the now-unused column
+    /// beneath it -- mirroring `test_projection_aggregation`. The parent
+    /// also keeps a passthrough column (`a`) so the outer projection isn't
+    /// itself a no-op once the Window narrows to one surviving function.
+    #[test]
+    fn test_projection_window_drops_unused_window_function() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+            Field::new("c", DataType::Int64),
+        ]);
+        let window_spec = WindowSpec {
+            partition_by: vec![unresolved_col("a")],
+            order_by: vec![],
+            ..Default::default()
+        };
+        let window_functions = vec![
+            unresolved_col("b").alias("w1"),
+            unresolved_col("c").alias("w2"),
+        ];
+        let window: LogicalPlan = Window::try_new(
+            dummy_scan_node(scan_op.clone()).build(),
+            window_functions,
+            window_spec.clone(),
+        )?
+        .into();
+        let plan = LogicalPlan::Project(Project::try_new(
+            window.into(),
+            vec![unresolved_col("a"), unresolved_col("w1")],
+        )?)
+        .arced();
+
+        let proj_pushdown = vec!["a".to_string(), "b".to_string()];
+        let expected_scan = dummy_scan_node_with_pushdowns(
+            scan_op,
+            Pushdowns::default().with_columns(Some(Arc::new(proj_pushdown))),
+        )
+        .build();
+        let expected_window: LogicalPlan = Window::try_new(
+            expected_scan,
+            vec![unresolved_col("b").alias("w1")],
+            window_spec,
+        )?
+        .into();
+        let expected = LogicalPlan::Project(Project::try_new(
+            expected_window.into(),
+            vec![unresolved_col("a"), unresolved_col("w1")],
+        )?)
+        .arced();
+
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Projection<-Window: when every window function is dropped, the
+    /// Window node itself collapses to its input.
+    #[test]
+    fn test_projection_window_drops_entire_window() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]);
+        let window_spec = WindowSpec {
+            partition_by: vec![unresolved_col("a")],
+            order_by: vec![],
+            ..Default::default()
+        };
+        let window: LogicalPlan = Window::try_new(
+            dummy_scan_node(scan_op.clone()).build(),
+            vec![unresolved_col("b").alias("w1")],
+            window_spec,
+        )?
+        .into();
+        let plan = LogicalPlan::Project(Project::try_new(
+            window.into(),
+            vec![unresolved_col("a")],
+        )?)
+        .arced();
+
+        let proj_pushdown = vec!["a".to_string()];
+        let expected = dummy_scan_node_with_pushdowns(
+            scan_op,
+            Pushdowns::default().with_columns(Some(Arc::new(proj_pushdown))),
+        )
+        .build();
+
+        assert_optimized_plan_eq(plan, expected)?;
+        Ok(())
+    }
+
+    /// Projection<-Concat (the runtime form of a `Union`) pushes the
+    /// combined, position-matched column subset into both sides.
+    #[test]
+    fn test_projection_pushdown_through_concat() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+            Field::new("c", DataType::Int64),
+        ]);
+        let left = dummy_scan_node(scan_op.clone()).build();
+        let right = dummy_scan_node(scan_op.clone()).build();
+        let plan = LogicalPlan::Concat(Concat::try_new(left, right)?).into();
+        let plan = LogicalPlan::Project(Project::try_new(plan, vec![unresolved_col("a")])?).into();
+
+        let proj_pushdown = vec!["a".to_string()];
+        let expected_left = dummy_scan_node_with_pushdowns(
+            scan_op.clone(),
+            Pushdowns::default().with_columns(Some(Arc::new(proj_pushdown.clone()))),
+        )
+        .build();
+        let expected_right = dummy_scan_node_with_pushdowns(
+            scan_op,
+            Pushdowns::default().with_columns(Some(Arc::new(proj_pushdown))),
+        )
+        .build();
+        let expected = LogicalPlan::Concat(Concat::try_new(expected_left, expected_right)?).into();
+        let expected =
+            LogicalPlan::Project(Project::try_new(expected, vec![unresolved_col("a")])?).into();
+
+        assert_optimized_plan_eq(plan, expected)?;
+
+        Ok(())
+    }
+
+    /// `try_optimize_worklist` drives its traversal from an explicit
+    /// heap-allocated stack rather than recursing through
+    /// `TreeNode::transform_up`, specifically so a deeply nested plan can't
+    /// blow the call stack. Build a long chain of `Filter` nodes -- each
+    /// already requiring every column the scan provides, so no pushdown
+    /// triggers anywhere along the chain -- topped by a renaming `Project`
+    /// (so it isn't dropped as a no-op), and check the plan comes back
+    /// unchanged instead of overflowing.
+    #[test]
+    fn test_deeply_nested_plan_does_not_overflow_stack() -> DaftResult<()> {
+        let scan_op = dummy_scan_operator(vec![Field::new("a", DataType::Int64)]);
+        let mut builder = dummy_scan_node(scan_op);
+        for _ in 0..5000 {
+            builder = builder.filter(unresolved_col("a").eq(lit(0)))?;
+        }
+        let plan = builder.select(vec![unresolved_col("a").alias("b")])?.build();
+
+        assert_optimized_plan_eq(plan.clone(), plan)?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,234 @@
+//! Compiles a filter predicate (as collected into `Pushdowns.filters`) into
+//! a pruning predicate evaluated against per-file/per-row-group statistics,
+//! so scan planning can skip a row group outright when its statistics prove
+//! no row in it can satisfy the filter.
+//!
+//! This is the predicate-compilation half of statistics-based row-group
+//! pruning: it turns `col <op> literal` conjuncts into a "this group might
+//! contain a matching row" expression over that column's `{col}_min` /
+//! `{col}_max` / `{col}_null_count` statistics columns. Wiring the compiled
+//! predicate up to an actual per-row-group statistics table and dropping
+//! scan tasks it evaluates false against is the scan-task layer's job
+//! (`common_scan_info`'s `ScanTask`/`PhysicalScanInfo`), which isn't part of
+//! this checked-out snapshot -- this module only owns the translation step,
+//! which is fully self-contained and independently testable.
+
+use daft_dsl::{resolved_col, Column, Expr, ExprRef, LiteralValue, Operator, ResolvedColumn};
+
+fn min_col(col: &str) -> ExprRef {
+    resolved_col(format!("{col}_min"))
+}
+
+fn max_col(col: &str) -> ExprRef {
+    resolved_col(format!("{col}_max"))
+}
+
+fn null_count_col(col: &str) -> ExprRef {
+    resolved_col(format!("{col}_null_count"))
+}
+
+fn as_column_name(expr: &ExprRef) -> Option<String> {
+    match expr.as_ref() {
+        Expr::Column(Column::Resolved(ResolvedColumn::Basic(name))) => Some(name.to_string()),
+        _ => None,
+    }
+}
+
+fn as_literal(expr: &ExprRef) -> Option<LiteralValue> {
+    match expr.as_ref() {
+        Expr::Literal(literal) => Some(literal.clone()),
+        _ => None,
+    }
+}
+
+/// Flips an operator to its mirror image, for normalizing `literal <op> col`
+/// to `col <flipped op> literal`. Returns `None` for operators that aren't
+/// simple binary comparisons.
+fn flip(op: Operator) -> Option<Operator> {
+    match op {
+        Operator::Eq => Some(Operator::Eq),
+        Operator::NotEq => Some(Operator::NotEq),
+        Operator::Lt => Some(Operator::Gt),
+        Operator::LtEq => Some(Operator::GtEq),
+        Operator::Gt => Some(Operator::Lt),
+        Operator::GtEq => Some(Operator::LtEq),
+        _ => None,
+    }
+}
+
+fn and(left: ExprRef, right: ExprRef) -> ExprRef {
+    Expr::BinaryOp {
+        op: Operator::And,
+        left,
+        right,
+    }
+    .arced()
+}
+
+fn compare(op: Operator, left: ExprRef, right: ExprRef) -> ExprRef {
+    Expr::BinaryOp { op, left, right }.arced()
+}
+
+/// Splits the top-level `AND`-conjuncts of a predicate, recursing through
+/// nested `AND`s so e.g. `a AND (b AND c)` yields `[a, b, c]`.
+fn split_conjuncts(expr: &ExprRef) -> Vec<ExprRef> {
+    match expr.as_ref() {
+        Expr::BinaryOp {
+            op: Operator::And,
+            left,
+            right,
+        } => {
+            let mut conjuncts = split_conjuncts(left);
+            conjuncts.extend(split_conjuncts(right));
+            conjuncts
+        }
+        _ => vec![expr.clone()],
+    }
+}
+
+/// Rewrites a single conjunct of the form `col <op> literal` (or
+/// `literal <op> col`) into a "this group might contain a matching row"
+/// expression over that column's statistics, given an expression for the
+/// group's total row count (e.g. a `num_rows` statistics column).
+///
+/// Returns `None` for any conjunct shape this can't translate -- equality
+/// against a non-literal, a non-comparison operator, an expression over
+/// more than one column, etc. -- so the caller can conservatively treat it
+/// as "keep" rather than dropping rows that might still match.
+fn try_prune_conjunct(expr: &ExprRef, row_count: &ExprRef) -> Option<ExprRef> {
+    let Expr::BinaryOp { op, left, right } = expr.as_ref() else {
+        return None;
+    };
+
+    let (col, op, literal) = if let (Some(col), Some(literal)) =
+        (as_column_name(left), as_literal(right))
+    {
+        (col, *op, literal)
+    } else if let (Some(literal), Some(col)) = (as_literal(left), as_column_name(right)) {
+        (col, flip(*op)?, literal)
+    } else {
+        return None;
+    };
+
+    let literal_expr = Expr::Literal(literal).arced();
+    let min = min_col(&col);
+    let max = max_col(&col);
+
+    // A value can only be within [min, max] for the operator to possibly
+    // hold; `!=` can't be ruled out from min/max alone (a single matching
+    // value anywhere in the range makes it unprunable without an exact
+    // distinct-values sketch), so it's conservatively left untranslated.
+    let range_check = match op {
+        Operator::Eq => and(
+            compare(Operator::LtEq, min, literal_expr.clone()),
+            compare(Operator::GtEq, max, literal_expr),
+        ),
+        Operator::Lt => compare(Operator::Lt, min, literal_expr),
+        Operator::LtEq => compare(Operator::LtEq, min, literal_expr),
+        Operator::Gt => compare(Operator::Gt, max, literal_expr),
+        Operator::GtEq => compare(Operator::GtEq, max, literal_expr),
+        _ => return None,
+    };
+
+    // Nulls never satisfy a comparison, so a group made up entirely of
+    // nulls (`null_count == row_count`) can't contain a match regardless of
+    // what its (otherwise undefined) min/max say.
+    let has_non_null = compare(Operator::Lt, null_count_col(&col), row_count.clone());
+
+    Some(and(range_check, has_non_null))
+}
+
+/// Compiles `predicate` into a "this group might contain matching rows"
+/// expression over per-group min/max/null-count statistics columns, or
+/// `None` if no conjunct of `predicate` could be translated (meaning
+/// nothing can be pruned for it at all).
+///
+/// Untranslatable conjuncts are dropped from the result rather than making
+/// the whole predicate untranslatable: each translated conjunct is still a
+/// valid necessary condition on its own, so ANDing together only the ones
+/// that could be compiled is still sound, just less precise than if every
+/// conjunct contributed.
+pub fn build_pruning_predicate(predicate: &ExprRef, row_count: &ExprRef) -> Option<ExprRef> {
+    split_conjuncts(predicate)
+        .iter()
+        .filter_map(|conjunct| try_prune_conjunct(conjunct, row_count))
+        .reduce(and)
+}
+
+#[cfg(test)]
+mod tests {
+    use daft_dsl::lit;
+
+    use super::*;
+
+    fn row_count() -> ExprRef {
+        resolved_col("num_rows")
+    }
+
+    /// `col == literal` compiles to a min/max range check ANDed with a
+    /// not-all-null check.
+    #[test]
+    fn test_eq_compiles_to_range_and_non_null_check() {
+        let predicate = compare(Operator::Eq, resolved_col("a"), lit(5));
+        let pruning = build_pruning_predicate(&predicate, &row_count()).unwrap();
+
+        let expected = and(
+            and(
+                compare(Operator::LtEq, min_col("a"), lit(5)),
+                compare(Operator::GtEq, max_col("a"), lit(5)),
+            ),
+            compare(Operator::Lt, null_count_col("a"), row_count()),
+        );
+        assert_eq!(pruning, expected);
+    }
+
+    /// `col < literal` only needs the column's min to possibly be below the
+    /// literal.
+    #[test]
+    fn test_lt_compiles_to_min_check() {
+        let predicate = compare(Operator::Lt, resolved_col("a"), lit(5));
+        let pruning = build_pruning_predicate(&predicate, &row_count()).unwrap();
+
+        let expected = and(
+            compare(Operator::Lt, min_col("a"), lit(5)),
+            compare(Operator::Lt, null_count_col("a"), row_count()),
+        );
+        assert_eq!(pruning, expected);
+    }
+
+    /// `literal > col` is normalized (flipped) to the equivalent `col < literal`
+    /// shape before translation.
+    #[test]
+    fn test_literal_on_left_is_flipped() {
+        let predicate = compare(Operator::Gt, lit(5), resolved_col("a"));
+        let flipped = compare(Operator::Lt, resolved_col("a"), lit(5));
+
+        let pruning = build_pruning_predicate(&predicate, &row_count()).unwrap();
+        let expected = build_pruning_predicate(&flipped, &row_count()).unwrap();
+        assert_eq!(pruning, expected);
+    }
+
+    /// `col != literal` can't be ruled out from min/max alone, so it's left
+    /// untranslated -- with no other conjunct, the whole predicate compiles
+    /// to nothing.
+    #[test]
+    fn test_not_eq_is_untranslatable() {
+        let predicate = compare(Operator::NotEq, resolved_col("a"), lit(5));
+        assert!(build_pruning_predicate(&predicate, &row_count()).is_none());
+    }
+
+    /// A conjunct that can't be translated (here, an equality between two
+    /// columns rather than a column and a literal) is dropped rather than
+    /// making the whole predicate untranslatable: only the translatable
+    /// conjunct contributes to the compiled result.
+    #[test]
+    fn test_untranslatable_conjunct_is_dropped_not_fatal() {
+        let translatable = compare(Operator::Lt, resolved_col("a"), lit(5));
+        let untranslatable = compare(Operator::Eq, resolved_col("a"), resolved_col("b"));
+        let predicate = and(translatable.clone(), untranslatable);
+
+        let pruning = build_pruning_predicate(&predicate, &row_count()).unwrap();
+        let expected = build_pruning_predicate(&translatable, &row_count()).unwrap();
+        assert_eq!(pruning, expected);
+    }
+}
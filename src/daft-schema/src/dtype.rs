@@ -0,0 +1,279 @@
+use std::{
+    fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{field::Field, image_mode::ImageMode, time_unit::TimeUnit};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum DataType {
+    Null,
+    Boolean,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
+    Float32,
+    Float64,
+    Utf8,
+    Binary,
+    Date,
+    Time(TimeUnit),
+    Timestamp(TimeUnit, Option<String>),
+    List(Box<DataType>),
+    FixedSizeList(Box<DataType>, usize),
+    Struct(Vec<Field>),
+    Image(Option<ImageMode>),
+    FixedShapeImage(ImageMode, u32, u32),
+    Python,
+    /// An Arrow extension type: `(extension_name, storage_type, extension_metadata)`.
+    ///
+    /// Unlike a field's `metadata` map (which field equality ignores),
+    /// `Extension` makes the extension name and metadata part of the type
+    /// itself, so two fields with the same storage type but different
+    /// extension names are not the same `DataType` for equality or hashing.
+    /// [`DataType::to_physical`] still resolves it to its storage type.
+    Extension(String, Box<DataType>, Option<String>),
+}
+
+impl Display for DataType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Null => write!(f, "Null"),
+            Self::Boolean => write!(f, "Boolean"),
+            Self::Int8 => write!(f, "Int8"),
+            Self::Int16 => write!(f, "Int16"),
+            Self::Int32 => write!(f, "Int32"),
+            Self::Int64 => write!(f, "Int64"),
+            Self::UInt8 => write!(f, "UInt8"),
+            Self::UInt16 => write!(f, "UInt16"),
+            Self::UInt32 => write!(f, "UInt32"),
+            Self::UInt64 => write!(f, "UInt64"),
+            Self::Float32 => write!(f, "Float32"),
+            Self::Float64 => write!(f, "Float64"),
+            Self::Utf8 => write!(f, "Utf8"),
+            Self::Binary => write!(f, "Binary"),
+            Self::Date => write!(f, "Date"),
+            Self::Time(unit) => write!(f, "Time({unit})"),
+            Self::Timestamp(unit, tz) => write!(f, "Timestamp({unit}, {tz:?})"),
+            Self::List(inner) => write!(f, "List[{inner}]"),
+            Self::FixedSizeList(inner, size) => write!(f, "FixedSizeList[{inner}; {size}]"),
+            Self::Struct(fields) => {
+                write!(f, "Struct[")?;
+                for (i, field) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", field.name, field.dtype)?;
+                }
+                write!(f, "]")
+            }
+            Self::Image(mode) => write!(f, "Image[{mode:?}]"),
+            Self::FixedShapeImage(mode, h, w) => write!(f, "Image[{mode}; {h} x {w}]"),
+            Self::Python => write!(f, "Python"),
+            Self::Extension(name, storage, _) => write!(f, "Extension[{name}, {storage}]"),
+        }
+    }
+}
+
+impl PartialEq for DataType {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Null, Self::Null)
+            | (Self::Boolean, Self::Boolean)
+            | (Self::Int8, Self::Int8)
+            | (Self::Int16, Self::Int16)
+            | (Self::Int32, Self::Int32)
+            | (Self::Int64, Self::Int64)
+            | (Self::UInt8, Self::UInt8)
+            | (Self::UInt16, Self::UInt16)
+            | (Self::UInt32, Self::UInt32)
+            | (Self::UInt64, Self::UInt64)
+            | (Self::Float32, Self::Float32)
+            | (Self::Float64, Self::Float64)
+            | (Self::Utf8, Self::Utf8)
+            | (Self::Binary, Self::Binary)
+            | (Self::Date, Self::Date)
+            | (Self::Python, Self::Python) => true,
+            (Self::Time(a), Self::Time(b)) => a == b,
+            (Self::Timestamp(a_unit, a_tz), Self::Timestamp(b_unit, b_tz)) => {
+                a_unit == b_unit && a_tz == b_tz
+            }
+            (Self::List(a), Self::List(b)) => a == b,
+            (Self::FixedSizeList(a_dtype, a_size), Self::FixedSizeList(b_dtype, b_size)) => {
+                a_dtype == b_dtype && a_size == b_size
+            }
+            (Self::Struct(a), Self::Struct(b)) => a == b,
+            (Self::Image(a), Self::Image(b)) => a == b,
+            (Self::FixedShapeImage(a_mode, a_h, a_w), Self::FixedShapeImage(b_mode, b_h, b_w)) => {
+                a_mode == b_mode && a_h == b_h && a_w == b_w
+            }
+            (
+                Self::Extension(a_name, a_storage, a_metadata),
+                Self::Extension(b_name, b_storage, b_metadata),
+            ) => a_name == b_name && a_storage == b_storage && a_metadata == b_metadata,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for DataType {}
+
+impl Hash for DataType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Time(unit) => unit.hash(state),
+            Self::Timestamp(unit, tz) => {
+                unit.hash(state);
+                tz.hash(state);
+            }
+            Self::List(inner) => inner.hash(state),
+            Self::FixedSizeList(inner, size) => {
+                inner.hash(state);
+                size.hash(state);
+            }
+            Self::Struct(fields) => fields.hash(state),
+            Self::Image(mode) => mode.hash(state),
+            Self::FixedShapeImage(mode, h, w) => {
+                mode.hash(state);
+                h.hash(state);
+                w.hash(state);
+            }
+            Self::Extension(name, storage, metadata) => {
+                name.hash(state);
+                storage.hash(state);
+                metadata.hash(state);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl DataType {
+    pub fn is_python(&self) -> bool {
+        matches!(self, Self::Python)
+    }
+
+    pub fn is_extension_type(&self) -> bool {
+        matches!(self, Self::Extension(..))
+    }
+
+    /// This type's own storage representation: the extension name and
+    /// metadata carried by `Extension` are a schema-level tag, not a
+    /// distinct physical layout, so they resolve to their storage type here.
+    pub fn to_physical(&self) -> Self {
+        match self {
+            Self::Extension(_, storage, _) => storage.to_physical(),
+            Self::List(inner) => Self::List(Box::new(inner.to_physical())),
+            Self::FixedSizeList(inner, size) => {
+                Self::FixedSizeList(Box::new(inner.to_physical()), *size)
+            }
+            Self::Struct(fields) => Self::Struct(
+                fields
+                    .iter()
+                    .map(|f| Field::new(f.name.clone(), f.dtype.to_physical()))
+                    .collect(),
+            ),
+            other => other.clone(),
+        }
+    }
+
+    pub fn to_arrow(&self) -> common_error::DaftResult<arrow2::datatypes::DataType> {
+        use arrow2::datatypes::DataType as ArrowType;
+        Ok(match self {
+            Self::Null => ArrowType::Null,
+            Self::Boolean => ArrowType::Boolean,
+            Self::Int8 => ArrowType::Int8,
+            Self::Int16 => ArrowType::Int16,
+            Self::Int32 => ArrowType::Int32,
+            Self::Int64 => ArrowType::Int64,
+            Self::UInt8 => ArrowType::UInt8,
+            Self::UInt16 => ArrowType::UInt16,
+            Self::UInt32 => ArrowType::UInt32,
+            Self::UInt64 => ArrowType::UInt64,
+            Self::Float32 => ArrowType::Float32,
+            Self::Float64 => ArrowType::Float64,
+            Self::Utf8 => ArrowType::LargeUtf8,
+            Self::Binary => ArrowType::LargeBinary,
+            Self::Date => ArrowType::Date32,
+            Self::Time(unit) => ArrowType::Time64((*unit).into()),
+            Self::Timestamp(unit, tz) => ArrowType::Timestamp((*unit).into(), tz.clone()),
+            Self::List(inner) => ArrowType::LargeList(Box::new(arrow2::datatypes::Field::new(
+                "item",
+                inner.to_arrow()?,
+                true,
+            ))),
+            Self::FixedSizeList(inner, size) => ArrowType::FixedSizeList(
+                Box::new(arrow2::datatypes::Field::new(
+                    "item",
+                    inner.to_arrow()?,
+                    true,
+                )),
+                *size,
+            ),
+            Self::Struct(fields) => ArrowType::Struct(
+                fields
+                    .iter()
+                    .map(|f| f.to_arrow())
+                    .collect::<common_error::DaftResult<Vec<_>>>()?,
+            ),
+            Self::Image(..) | Self::FixedShapeImage(..) | Self::Python => {
+                return Err(common_error::DaftError::TypeError(format!(
+                    "Cannot convert {self} to an Arrow type"
+                )))
+            }
+            Self::Extension(name, storage, metadata) => ArrowType::Extension(
+                name.clone(),
+                Box::new(storage.to_arrow()?),
+                metadata.clone(),
+            ),
+        })
+    }
+}
+
+impl From<&arrow2::datatypes::DataType> for DataType {
+    fn from(arrow_type: &arrow2::datatypes::DataType) -> Self {
+        use arrow2::datatypes::DataType as ArrowType;
+        match arrow_type {
+            ArrowType::Null => Self::Null,
+            ArrowType::Boolean => Self::Boolean,
+            ArrowType::Int8 => Self::Int8,
+            ArrowType::Int16 => Self::Int16,
+            ArrowType::Int32 => Self::Int32,
+            ArrowType::Int64 => Self::Int64,
+            ArrowType::UInt8 => Self::UInt8,
+            ArrowType::UInt16 => Self::UInt16,
+            ArrowType::UInt32 => Self::UInt32,
+            ArrowType::UInt64 => Self::UInt64,
+            ArrowType::Float32 => Self::Float32,
+            ArrowType::Float64 => Self::Float64,
+            ArrowType::Utf8 | ArrowType::LargeUtf8 => Self::Utf8,
+            ArrowType::Binary | ArrowType::LargeBinary => Self::Binary,
+            ArrowType::Date32 | ArrowType::Date64 => Self::Date,
+            ArrowType::Time64(unit) | ArrowType::Time32(unit) => Self::Time(unit.into()),
+            ArrowType::Timestamp(unit, tz) => Self::Timestamp(unit.into(), tz.clone()),
+            ArrowType::List(field) | ArrowType::LargeList(field) => {
+                Self::List(Box::new(field.data_type().into()))
+            }
+            ArrowType::FixedSizeList(field, size) => {
+                Self::FixedSizeList(Box::new(field.data_type().into()), *size)
+            }
+            ArrowType::Struct(fields) => Self::Struct(fields.iter().map(Field::from).collect()),
+            ArrowType::Extension(name, storage, metadata) => Self::Extension(
+                name.clone(),
+                Box::new(storage.as_ref().into()),
+                metadata.clone(),
+            ),
+            other => panic!(
+                "DataType::from(&arrow2::datatypes::DataType) is not implemented for {other:?}"
+            ),
+        }
+    }
+}
@@ -128,6 +128,55 @@ impl Field {
         })
     }
 
+    /// Tags this field as carrying values of a user-defined Arrow extension
+    /// type, wrapping its current `dtype` as the extension's storage type.
+    ///
+    /// Unlike the field's `metadata` map (which [`Self::eq`]/[`Self::hash`]
+    /// deliberately ignore), wrapping the `dtype` in [`DataType::Extension`]
+    /// makes the extension name and metadata part of the type itself, so two
+    /// fields that otherwise share a storage type but declare different
+    /// extension types are no longer considered the same `DataType`. Calling
+    /// this again on an already-tagged field replaces the existing
+    /// extension tag rather than nesting two `Extension` layers.
+    pub fn with_extension_type<S: Into<String>>(
+        self,
+        extension_name: S,
+        extension_metadata: Option<String>,
+    ) -> Self {
+        let storage = match self.dtype {
+            DataType::Extension(_, storage, _) => storage,
+            other => Box::new(other),
+        };
+        Self {
+            name: self.name,
+            dtype: DataType::Extension(extension_name.into(), storage, extension_metadata),
+            metadata: self.metadata,
+        }
+    }
+
+    /// Returns this field's Arrow extension type name, if it has been tagged
+    /// with one via [`Self::with_extension_type`] or carried over from an
+    /// Arrow schema that declared one.
+    pub fn extension_name(&self) -> Option<&str> {
+        match &self.dtype {
+            DataType::Extension(name, ..) => Some(name.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Returns this field's opaque Arrow extension type metadata, if any.
+    pub fn extension_metadata(&self) -> Option<&str> {
+        match &self.dtype {
+            DataType::Extension(_, _, metadata) => metadata.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// True if this field is tagged as an Arrow extension type.
+    pub fn is_extension_type(&self) -> bool {
+        self.dtype.is_extension_type()
+    }
+
     pub fn to_exploded_field(&self) -> DaftResult<Self> {
         match &self.dtype {
             DataType::List(child_dtype) | DataType::FixedSizeList(child_dtype, _) => {
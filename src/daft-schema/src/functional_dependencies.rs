@@ -0,0 +1,131 @@
+//! Functional-dependency metadata for a schema, borrowed from DataFusion's
+//! `DFSchema` functional-dependency tracking.
+//!
+//! A functional dependency `source_indices -> dependent_indices` records
+//! that the values at `source_indices` uniquely determine the values at
+//! `dependent_indices` within a schema's field list (e.g. a GROUP BY key
+//! determines every value produced by the aggregations grouped on it).
+//! Optimizer passes can consult [`FunctionalDependencies`] to prune columns
+//! that carry no additional information instead of re-deriving the
+//! relationship from scratch at every site that needs it.
+
+use serde::{Deserialize, Serialize};
+
+/// How a [`FunctionalDependence`] was established.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Dependency {
+    /// `source_indices` are guaranteed unique (e.g. a primary key or a set
+    /// of GROUP BY keys), so they functionally determine every other field
+    /// in the schema.
+    Single,
+    /// `source_indices` functionally determine `dependent_indices`
+    /// specifically, not necessarily every other field in the schema.
+    Multi,
+}
+
+/// A single functional dependency: `source_indices` determine
+/// `dependent_indices`. Both are positions into the owning schema's field
+/// list.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionalDependence {
+    pub source_indices: Vec<usize>,
+    pub dependent_indices: Vec<usize>,
+    pub mode: Dependency,
+}
+
+impl FunctionalDependence {
+    pub fn new(
+        source_indices: Vec<usize>,
+        dependent_indices: Vec<usize>,
+        mode: Dependency,
+    ) -> Self {
+        Self {
+            source_indices,
+            dependent_indices,
+            mode,
+        }
+    }
+}
+
+/// A set of [`FunctionalDependence`]s over one schema's fields.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionalDependencies {
+    deps: Vec<FunctionalDependence>,
+}
+
+impl FunctionalDependencies {
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Builds a set asserting that `source_indices` determine every other
+    /// field in a schema of `num_fields` fields, e.g. the GROUP BY keys of
+    /// an `Aggregate` determine every aggregated output.
+    pub fn new_from_constraint(source_indices: Vec<usize>, num_fields: usize) -> Self {
+        let dependent_indices = (0..num_fields)
+            .filter(|i| !source_indices.contains(i))
+            .collect();
+        Self {
+            deps: vec![FunctionalDependence::new(
+                source_indices,
+                dependent_indices,
+                Dependency::Single,
+            )],
+        }
+    }
+
+    pub fn push(&mut self, dependence: FunctionalDependence) {
+        self.deps.push(dependence);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &FunctionalDependence> {
+        self.deps.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.deps.is_empty()
+    }
+
+    /// Returns true if every index referenced by every dependency is
+    /// `< num_fields`. Call this after building or merging dependencies
+    /// (e.g. after a projection drops fields without remapping indices) to
+    /// catch stale references before they're relied on.
+    pub fn is_valid(&self, num_fields: usize) -> bool {
+        self.deps.iter().all(|dep| {
+            dep.source_indices.iter().all(|&i| i < num_fields)
+                && dep.dependent_indices.iter().all(|&i| i < num_fields)
+        })
+    }
+
+    /// Returns true if `indices` is guaranteed to contain no duplicate
+    /// rows, because some known dependency's `source_indices` -- which by
+    /// `Dependency::Single` definition already determine every other field
+    /// -- are all present in `indices`. A superset of a key is itself
+    /// trivially a key, so e.g. a `DISTINCT` over a superset of a known key
+    /// can't remove any rows and is redundant.
+    pub fn is_superkey(&self, indices: &[usize]) -> bool {
+        self.deps.iter().any(|dep| {
+            dep.mode == Dependency::Single && dep.source_indices.iter().all(|i| indices.contains(i))
+        })
+    }
+
+    /// Returns the subset of `indices` that are functionally redundant:
+    /// each returned index is a `dependent_indices` member of some
+    /// dependency whose `source_indices` are all present in `indices`
+    /// (other than the index itself).
+    pub fn redundant_indices(&self, indices: &[usize]) -> Vec<usize> {
+        indices
+            .iter()
+            .copied()
+            .filter(|&idx| {
+                self.deps.iter().any(|dep| {
+                    dep.dependent_indices.contains(&idx)
+                        && dep
+                            .source_indices
+                            .iter()
+                            .all(|source| *source != idx && indices.contains(source))
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,37 @@
+//! The pixel layout of an `Image`/`FixedShapeImage` [`crate::dtype::DataType`].
+
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ImageMode {
+    L,
+    LA,
+    RGB,
+    RGBA,
+    L16,
+    LA16,
+    RGB16,
+    RGBA16,
+    RGB32F,
+    RGBA32F,
+}
+
+impl Display for ImageMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl ImageMode {
+    /// Number of channels an image in this mode carries per pixel.
+    pub fn num_channels(self) -> u16 {
+        match self {
+            Self::L | Self::L16 => 1,
+            Self::LA | Self::LA16 => 2,
+            Self::RGB | Self::RGB16 | Self::RGB32F => 3,
+            Self::RGBA | Self::RGBA16 | Self::RGBA32F => 4,
+        }
+    }
+}
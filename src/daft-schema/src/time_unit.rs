@@ -0,0 +1,60 @@
+//! The unit a `Time`/`Timestamp` [`crate::dtype::DataType`] counts ticks in.
+
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TimeUnit {
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl Display for TimeUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Seconds => "s",
+            Self::Milliseconds => "ms",
+            Self::Microseconds => "us",
+            Self::Nanoseconds => "ns",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl TimeUnit {
+    /// The number of `self`-ticks in one second, for converting a physical
+    /// `Time`/`Timestamp` value to/from whole seconds.
+    pub fn to_scale_factor(self) -> i64 {
+        match self {
+            Self::Seconds => 1,
+            Self::Milliseconds => 1_000,
+            Self::Microseconds => 1_000_000,
+            Self::Nanoseconds => 1_000_000_000,
+        }
+    }
+}
+
+impl From<&arrow2::datatypes::TimeUnit> for TimeUnit {
+    fn from(tu: &arrow2::datatypes::TimeUnit) -> Self {
+        match tu {
+            arrow2::datatypes::TimeUnit::Second => Self::Seconds,
+            arrow2::datatypes::TimeUnit::Millisecond => Self::Milliseconds,
+            arrow2::datatypes::TimeUnit::Microsecond => Self::Microseconds,
+            arrow2::datatypes::TimeUnit::Nanosecond => Self::Nanoseconds,
+        }
+    }
+}
+
+impl From<TimeUnit> for arrow2::datatypes::TimeUnit {
+    fn from(tu: TimeUnit) -> Self {
+        match tu {
+            TimeUnit::Seconds => Self::Second,
+            TimeUnit::Milliseconds => Self::Millisecond,
+            TimeUnit::Microseconds => Self::Microsecond,
+            TimeUnit::Nanoseconds => Self::Nanosecond,
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use std::{
+    fs::File,
+    io::BufWriter,
+    path::PathBuf,
+    sync::Arc,
+};
+
+use common_error::DaftResult;
+use daft_core::{
+    prelude::{Schema, UInt64Array, Utf8Array},
+    series::IntoSeries,
+};
+use daft_micropartition::MicroPartition;
+use daft_recordbatch::RecordBatch;
+
+use crate::{FileWriter, WriterFactory};
+
+/// Creates [`IpcFileWriter`]s that each serialize their input to a separate
+/// Arrow IPC (Feather) stream file under `dir`, named `<file_idx>.arrow`.
+pub(crate) struct IpcWriterFactory {
+    dir: PathBuf,
+}
+
+impl IpcWriterFactory {
+    pub(crate) fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+impl WriterFactory for IpcWriterFactory {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<RecordBatch>;
+
+    fn create_writer(
+        &self,
+        file_idx: usize,
+        partition_values: Option<&RecordBatch>,
+    ) -> DaftResult<Box<dyn FileWriter<Input = Self::Input, Result = Self::Result>>> {
+        let path = self.dir.join(format!("{file_idx}.arrow"));
+        Ok(Box::new(IpcFileWriter::new(path, partition_values.cloned())?)
+            as Box<dyn FileWriter<Input = Self::Input, Result = Self::Result>>)
+    }
+}
+
+/// Writes each incoming [`MicroPartition`] as Arrow IPC stream batches to a
+/// single on-disk file. `bytes_written` counts the inputs' in-memory sizes;
+/// `bytes_per_file` reports the file's size on disk (falling back to that
+/// in-memory count if the file's metadata can't be read).
+/// `close()` returns a metadata [`RecordBatch`] describing the file (path,
+/// row count, batch count, uncompressed size) rather than just a write
+/// count, so callers have enough to register the output with a catalog.
+pub(crate) struct IpcFileWriter {
+    path: PathBuf,
+    writer: arrow2::io::ipc::write::StreamWriter<BufWriter<File>>,
+    partition_values: Option<RecordBatch>,
+    row_count: usize,
+    num_batches: usize,
+    uncompressed_size: usize,
+    schema_written: bool,
+}
+
+impl IpcFileWriter {
+    fn new(path: PathBuf, partition_values: Option<RecordBatch>) -> DaftResult<Self> {
+        let file = BufWriter::new(File::create(&path)?);
+        let writer = arrow2::io::ipc::write::StreamWriter::new(
+            file,
+            arrow2::io::ipc::write::WriteOptions { compression: None },
+        );
+        Ok(Self {
+            path,
+            writer,
+            partition_values,
+            row_count: 0,
+            num_batches: 0,
+            uncompressed_size: 0,
+            schema_written: false,
+        })
+    }
+}
+
+impl FileWriter for IpcFileWriter {
+    type Input = Arc<MicroPartition>;
+    type Result = Option<RecordBatch>;
+
+    fn write(&mut self, input: Self::Input) -> DaftResult<usize> {
+        let size_bytes = input.size_bytes()?.unwrap_or(0);
+        for table in input.get_tables()?.iter() {
+            let arrow_schema = table.schema.to_arrow()?;
+            if !self.schema_written {
+                self.writer.start(&arrow_schema, None)?;
+                self.schema_written = true;
+            }
+
+            let columns = table
+                .columns
+                .iter()
+                .map(|series| series.to_arrow())
+                .collect();
+            let chunk = arrow2::chunk::Chunk::new(columns);
+            self.writer.write(&chunk, None)?;
+
+            self.row_count += table.len();
+            self.num_batches += 1;
+        }
+        self.uncompressed_size += size_bytes;
+        Ok(size_bytes)
+    }
+
+    fn bytes_written(&self) -> usize {
+        self.uncompressed_size
+    }
+
+    fn bytes_per_file(&self) -> Vec<usize> {
+        let on_disk = std::fs::metadata(&self.path)
+            .map(|meta| meta.len() as usize)
+            .unwrap_or(self.uncompressed_size);
+        vec![on_disk]
+    }
+
+    fn close(&mut self) -> DaftResult<Self::Result> {
+        if self.schema_written {
+            self.writer.finish()?;
+        }
+
+        let path_series = Utf8Array::from_values(
+            "path",
+            std::iter::once(self.path.to_string_lossy().into_owned()),
+        )
+        .into_series();
+        let row_count_series =
+            UInt64Array::from_values("row_count", std::iter::once(self.row_count as u64))
+                .into_series();
+        let num_batches_series =
+            UInt64Array::from_values("num_batches", std::iter::once(self.num_batches as u64))
+                .into_series();
+        let size_series = UInt64Array::from_values(
+            "uncompressed_bytes",
+            std::iter::once(self.uncompressed_size as u64),
+        )
+        .into_series();
+
+        let metadata_table = RecordBatch::new_unchecked(
+            Schema::new(vec![
+                path_series.field().clone(),
+                row_count_series.field().clone(),
+                num_batches_series.field().clone(),
+                size_series.field().clone(),
+            ])
+            .unwrap(),
+            vec![
+                path_series.into(),
+                row_count_series.into(),
+                num_batches_series.into(),
+                size_series.into(),
+            ],
+            1,
+        );
+
+        if let Some(partition_values) = self.partition_values.take() {
+            let unioned = metadata_table.union(&partition_values)?;
+            Ok(Some(unioned))
+        } else {
+            Ok(Some(metadata_table))
+        }
+    }
+}
+